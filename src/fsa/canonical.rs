@@ -0,0 +1,153 @@
+use std::{collections::{HashMap, HashSet, VecDeque}, hash::Hash};
+
+use super::{Dfa, FromStateId, StateTransitionTable};
+
+impl<State, Input> Dfa<State, Input>
+    where State: Eq + Hash + Ord + Clone, Input: Eq + Hash + Clone
+{
+    /// Порядок обхода состояний в ширину от начальных состояний,
+    /// переходы из каждого состояния перебираются в порядке `inputs`.
+    fn bfs_order(&self, inputs: &[Input]) -> Vec<State> {
+        let mut visited = self.starting_states.iter().cloned().collect::<HashSet<State>>();
+        let mut order = vec![];
+        let mut states_to_process = self.starting_states.iter().cloned().collect::<VecDeque<State>>();
+
+        while let Some(state) = states_to_process.pop_front() {
+            for input in inputs {
+                let Some(out_state) = self.transitions.get(&(state.clone(), input.clone()))
+                    .and_then(|out_state| out_state.first())
+                else { continue; };
+
+                if visited.insert(out_state.clone()) {
+                    states_to_process.push_back(out_state.clone());
+                }
+            }
+
+            order.push(state);
+        }
+
+        order
+    }
+
+    /// Каноническая нумерация состояний.
+    ///
+    /// Состояния переименовываются в порядке обхода в ширину от начального состояния,
+    /// переходы из каждого состояния перебираются по возрастанию входных символов.
+    /// Недостижимые состояния (если есть) нумеруются последними в порядке `states`.
+    ///
+    /// Минимальные ДКА одного языка после канонической нумерации имеют одинаковые
+    /// множества состояний и функции переходов, независимо от исходных имён состояний.
+    /// Отображения состояний-комбинаций очищаются.
+    pub fn canonicalize(&mut self)
+        where State: FromStateId, Input: Ord
+    {
+        let mut inputs = self.inputs.clone();
+        inputs.sort();
+
+        let mut order = self.bfs_order(&inputs);
+        let reachable_states = order.iter().cloned().collect::<HashSet<State>>();
+
+        order.extend(
+            self.states.iter()
+                .filter(|state| !reachable_states.contains(state))
+                .cloned()
+        );
+
+        let renaming = order.iter()
+            .enumerate()
+            .map(|(id, state)| (state.clone(), State::from_state_id(id)))
+            .collect::<HashMap<State, State>>();
+
+        let rename = |state: &State| renaming[state].clone();
+
+        let mut transitions = StateTransitionTable::new();
+
+        for ((in_state, input), out_state) in self.transitions.iter() {
+            transitions.insert(
+                (rename(in_state), input.clone()),
+                out_state.iter().map(rename).collect()
+            );
+        }
+
+        let mut closing_states = self.closing_states.iter().map(rename).collect::<Vec<State>>();
+        closing_states.sort();
+
+        // Состояния-комбинации ссылаются на имена исходного автомата, которые после переименования
+        // могут совпасть с новыми состояниями, поэтому сведения о комбинациях не сохраняются
+        self.state_combo_to_state_map.clear();
+        self.state_to_state_combo_map.clear();
+
+        self.states = order.iter().map(rename).collect();
+        self.inputs = inputs;
+        self.transitions = transitions;
+        self.starting_states = self.starting_states.iter().map(rename).collect();
+        self.closing_states = closing_states;
+    }
+
+    /// Проверка изоморфизма: существует ли взаимно однозначное соответствие состояний,
+    /// сохраняющее начальные и заключительные состояния и функцию переходов.
+    ///
+    /// Соответствие строится одновременным обходом в ширину обоих автоматов,
+    /// поэтому сравниваются достижимые части, а также общее число состояний.
+    pub fn is_isomorphic<OtherState>(&self, other: &Dfa<OtherState, Input>) -> bool
+        where OtherState: Eq + Hash + Ord + Clone
+    {
+        if self.states.len() != other.states.len() ||
+            self.starting_states.len() != other.starting_states.len() ||
+            self.inputs.len() != other.inputs.len() ||
+            !self.inputs.iter().all(|input| other.inputs.contains(input))
+        {
+            return false;
+        }
+
+        let mut mapping = HashMap::<State, OtherState>::new();
+        let mut inverse_mapping = HashMap::<OtherState, State>::new();
+        let mut pairs_to_process = VecDeque::new();
+
+        // Сопоставить пару состояний; false, если одно из них уже сопоставлено другому
+        let mut bind = |state: &State, other_state: &OtherState, pairs_to_process: &mut VecDeque<(State, OtherState)>| {
+            match (mapping.get(state), inverse_mapping.get(other_state)) {
+                (Some(mapped), Some(inverse_mapped)) => mapped == other_state && inverse_mapped == state,
+                (None, None) => {
+                    mapping.insert(state.clone(), other_state.clone());
+                    inverse_mapping.insert(other_state.clone(), state.clone());
+                    pairs_to_process.push_back((state.clone(), other_state.clone()));
+
+                    true
+                },
+                _ => false
+            }
+        };
+
+        for (state, other_state) in self.starting_states.iter().zip(&other.starting_states) {
+            if !bind(state, other_state, &mut pairs_to_process) {
+                return false;
+            }
+        }
+
+        while let Some((state, other_state)) = pairs_to_process.pop_front() {
+            if self.closing_states.contains(&state) != other.closing_states.contains(&other_state) {
+                return false;
+            }
+
+            for input in &self.inputs {
+                let out_state = self.transitions.get(&(state.clone(), input.clone()))
+                    .and_then(|out_state| out_state.first());
+                let other_out_state = other.transitions.get(&(other_state.clone(), input.clone()))
+                    .and_then(|out_state| out_state.first());
+
+                match (out_state, other_out_state) {
+                    (None, None) => {},
+                    (Some(out_state), Some(other_out_state)) => {
+                        if !bind(out_state, other_out_state, &mut pairs_to_process) {
+                            return false;
+                        }
+                    },
+                    _ => return false
+                }
+            }
+        }
+
+        true
+    }
+}
@@ -1,12 +1,13 @@
-use std::{collections::HashMap, fmt::Display, hash::Hash};
+use std::{collections::{HashMap, HashSet}, fmt::Display, hash::Hash, str::FromStr};
 
-use crate::grammar::Grammar;
+use crate::grammar::{Grammar, GrammarError, RegularType};
 
-use super::{Nfa, FiniteAutomataError, StateTransitionTable};
+use super::{Nfa, FiniteAutomataError, FromStateId, StateAllocator, StateTransitionTable};
 
 /// Детерминированный конечный автомат принимает вид
 /// M = (Q, T, F, H, Z), где 
 /// 
+/// ```text
 ///     Q - конечное множество состояний автомата;
 /// 
 ///     T - конечное множество допустимых входных символов;
@@ -16,11 +17,12 @@ use super::{Nfa, FiniteAutomataError, StateTransitionTable};
 ///     H - конечное множество начальных состояний автомата;
 /// 
 ///     Z - множество заключительных состояний автомата Z ⊆ (подмножество) Q.
+/// ```
 #[derive(Clone, PartialEq, Eq)]
-pub struct Dfa<State: Eq + Hash, Input> {
+pub struct Dfa<State: Eq + Hash, Input: Eq + Hash> {
     pub states: Vec<State>,
     pub inputs: Vec<Input>,
-    pub transitions: StateTransitionTable,
+    pub transitions: StateTransitionTable<State, Input>,
     pub starting_states: Vec<State>,
     pub closing_states: Vec<State>,
 
@@ -28,27 +30,27 @@ pub struct Dfa<State: Eq + Hash, Input> {
     pub state_to_state_combo_map: HashMap<State, Vec<State>>,
 }
 
-impl<State: Copy + Eq + Hash, Input: Copy> Display for Dfa<State, Input> 
-    where String: From<State> + From<Input> 
+impl<State, Input> Display for Dfa<State, Input> 
+    where State: Eq + Hash + Display, Input: Eq + Hash + Display
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let states = self.states.iter()
-            .map(|sym| String::from(*sym))
+            .map(|sym| sym.to_string())
             .collect::<Vec<String>>()
             .join(", ");
 
         let inputs = self.inputs.iter()
-            .map(|sym| String::from(*sym))
+            .map(|sym| sym.to_string())
             .collect::<Vec<String>>()
             .join(", ");
 
         let starting_states = self.starting_states.iter()
-            .map(|sym| String::from(*sym))
+            .map(|sym| sym.to_string())
             .collect::<Vec<String>>()
             .join(", ");
 
         let closing_states = self.closing_states.iter()
-            .map(|sym| String::from(*sym))
+            .map(|sym| sym.to_string())
             .collect::<Vec<String>>()
             .join(", ");
 
@@ -56,24 +58,39 @@ impl<State: Copy + Eq + Hash, Input: Copy> Display for Dfa<State, Input>
     }
 }
 
-impl TryFrom<Grammar> for Dfa<char, char> {
+impl<State, Input> TryFrom<Grammar> for Dfa<State, Input>
+    where 
+        State: FromStr + FromStateId + Eq + Hash + Ord + Clone, 
+        Input: FromStr + Eq + Hash + Clone
+{
     type Error = FiniteAutomataError;
 
     fn try_from(grammar: Grammar) -> Result<Self, Self::Error> {
-        Nfa::<char, char>::try_from(grammar)
-            .and_then(|nfa| Ok(nfa.to_deterministic()))
+        Nfa::<State, Input>::try_from(grammar)
+            .map(|nfa| nfa.to_deterministic())
+    }
+}
+
+impl<State, Input> Dfa<State, Input> 
+    where State: FromStateId + Eq + Hash + Ord + Clone + Display, Input: Eq + Hash + Clone + Display
+{
+    /// Построение регулярной грамматики заданного вида, см. `Nfa::to_grammar`.
+    pub fn to_grammar(&self, regular_type: RegularType) -> Result<Grammar, GrammarError> {
+        self.clone().into_nfa().to_grammar(regular_type)
     }
 }
 
-impl Dfa<char, char> {
+impl<State, Input> Dfa<State, Input> 
+    where State: Eq + Hash + Ord + Clone, Input: Eq + Hash + Clone
+{
     pub fn new(
-        states: Vec<char>,
-        inputs: Vec<char>,
-        transitions: StateTransitionTable,
-        starting_states: Vec<char>,
-        closing_states: Vec<char>,
-        state_combo_to_state_map: HashMap<Vec<char>, char>,
-        state_to_state_combo_map: HashMap<char, Vec<char>>,
+        states: Vec<State>,
+        inputs: Vec<Input>,
+        transitions: StateTransitionTable<State, Input>,
+        starting_states: Vec<State>,
+        closing_states: Vec<State>,
+        state_combo_to_state_map: HashMap<Vec<State>, State>,
+        state_to_state_combo_map: HashMap<State, Vec<State>>,
     ) -> Result<Self, FiniteAutomataError> {
         // validate maps, transitions, starting/closing states
 
@@ -89,11 +106,20 @@ impl Dfa<char, char> {
         })
     }
 
-    pub fn to_non_deterministic(mut self) -> Nfa<char, char> {
+    pub fn to_non_deterministic(mut self) -> Nfa<State, Input> {
+        // Состояние-комбинацию можно заменить исходными состояниями, только если все они остались в автомате
+        // (при детерминизации ε-НКА состояния с нетривиальным ε-замыканием отдельно не сохраняются)
         let states_to_remove = self.states.iter()
-            .filter(|state| self.state_to_state_combo_map.contains_key(state))
+            .filter(|state| {
+                self.state_to_state_combo_map.get(state)
+                    .is_some_and(|combo| 
+                        combo.iter().all(|sub_state| 
+                            self.states.contains(sub_state) && !self.state_to_state_combo_map.contains_key(sub_state)
+                        )
+                    )
+            })
             .cloned()
-            .collect::<Vec<char>>();
+            .collect::<Vec<State>>();
 
         states_to_remove.iter()
             .for_each(|state_to_remove| {
@@ -107,7 +133,7 @@ impl Dfa<char, char> {
                 // remove transitions
                 self.inputs.iter()
                     .for_each(|input| {
-                        self.transitions.remove(&(*state_to_remove, *input));
+                        self.transitions.remove(&(state_to_remove.clone(), input.clone()));
                     });
 
                 // convert states to underlying combos
@@ -131,30 +157,95 @@ impl Dfa<char, char> {
             states: self.states,
             inputs: self.inputs,
             transitions: self.transitions,
+            epsilon_transitions: HashMap::new(),
+            starting_states: self.starting_states,
+            closing_states: self.closing_states
+        }
+    }
+
+    /// Тот же автомат как НКА, без замены состояний-комбинаций исходными состояниями.
+    pub(crate) fn into_nfa(self) -> Nfa<State, Input> {
+        Nfa {
+            states: self.states,
+            inputs: self.inputs,
+            transitions: self.transitions,
+            epsilon_transitions: HashMap::new(),
             starting_states: self.starting_states,
             closing_states: self.closing_states
         }
     }
 
+    /// Дополнение таблицы переходов до полной.
+    /// 
+    /// Все отсутствующие переходы ведут в новое тупиковое состояние, из которого
+    /// по любому символу автомат переходит в него же. Возвращает тупиковое состояние,
+    /// если его пришлось добавить.
+    pub fn complete(&mut self) -> Option<State> 
+        where State: FromStateId
+    {
+        let is_complete = !self.starting_states.is_empty() && self.states.iter()
+            .all(|state| 
+                self.inputs.iter().all(|input| self.transitions.get(&(state.clone(), input.clone())).is_some())
+            );
+
+        if is_complete { return None; }
+
+        let trap_state = StateAllocator::new(
+            self.states.iter().chain(self.state_to_state_combo_map.keys()).cloned()
+        ).allocate();
+
+        self.states.push(trap_state.clone());
+
+        for state in &self.states {
+            for input in &self.inputs {
+                let key = (state.clone(), input.clone());
+
+                if self.transitions.get(&key).is_none() {
+                    self.transitions.insert(key, vec![trap_state.clone()]);
+                }
+            }
+        }
+
+        // Автомат без начального состояния не допускает ни одной цепочки
+        if self.starting_states.is_empty() {
+            self.starting_states.push(trap_state.clone());
+        }
+
+        Some(trap_state)
+    }
+
+    /// Дополнение языка: автомат допускает ровно те цепочки над своим алфавитом,
+    /// которые не допускал исходный.
+    pub fn complement(mut self) -> Self 
+        where State: FromStateId
+    {
+        self.complete();
+
+        self.closing_states = self.states.iter()
+            .filter(|state| !self.closing_states.contains(state))
+            .cloned()
+            .collect();
+
+        self
+    }
+
     pub(crate) fn has_unreachable_states(&self) -> bool {
-        let mut reachable_states = vec![];
+        let mut reachable_states = HashSet::new();
 
         self.starting_states.iter()
-            .for_each(|state| reachable_states.push(*state));
+            .for_each(|state| { reachable_states.insert(state.clone()); });
 
-        let mut temp_vec = reachable_states.clone();
+        let mut temp_vec = reachable_states.iter().cloned().collect::<Vec<State>>();
 
         while let Some(state) = temp_vec.pop() {
             let vec = self.inputs.iter()
-                .map(|input| self.transitions.get(&(state, *input)).cloned().unwrap_or(vec![]))
-                .flatten()
-                .collect::<Vec<char>>();
+                .flat_map(|input| self.transitions.get(&(state.clone(), input.clone())).cloned().unwrap_or(vec![]))
+                .collect::<Vec<State>>();
 
             vec.iter()
                 .for_each(|state| {
-                    if !reachable_states.contains(state) {
-                        temp_vec.push(*state);
-                        reachable_states.push(*state);
+                    if reachable_states.insert(state.clone()) {
+                        temp_vec.push(state.clone());
                     }
                 });
         }
@@ -166,25 +257,24 @@ impl Dfa<char, char> {
         let mut reachable_states = vec![];
 
         self.starting_states.iter()
-            .for_each(|state| reachable_states.push(*state));
+            .for_each(|state| reachable_states.push(state.clone()));
 
         let mut temp_vec = reachable_states.clone();
 
         while let Some(state) = temp_vec.pop() {
             let vec = self.inputs.iter()
-                .map(|input| {
-                    self.transitions.get(&(state, *input))
+                .flat_map(|input| {
+                    self.transitions.get(&(state.clone(), input.clone()))
                         .cloned()
                         .unwrap_or(vec![])
                 })
-                .flatten()
-                .collect::<Vec<char>>();
+                .collect::<Vec<State>>();
 
             vec.iter()
                 .for_each(|state| {
                     if !reachable_states.contains(state) {
-                        temp_vec.push(*state);
-                        reachable_states.push(*state);
+                        temp_vec.push(state.clone());
+                        reachable_states.push(state.clone());
                     }
                 });
         }
@@ -192,7 +282,7 @@ impl Dfa<char, char> {
         let unreachable_states = self.states.iter()
             .filter(|state| !reachable_states.contains(state.to_owned()))
             .cloned()
-            .collect::<Vec<char>>();
+            .collect::<Vec<State>>();
 
         unreachable_states.iter().for_each(|unreachable_state| {
             // Убрать функции переходов
@@ -203,7 +293,7 @@ impl Dfa<char, char> {
                 )| unreachable_state == in_state || out_state.contains(unreachable_state))
                 .map(|(left_hand, _)| left_hand)
                 .cloned()
-                .collect::<Vec<(char, char)>>();
+                .collect::<Vec<(State, Input)>>();
             
             transitions_to_remove.iter()
                 .for_each(|left_hand| { self.transitions.remove(left_hand); });
@@ -226,61 +316,71 @@ impl Dfa<char, char> {
         });
     }
 
-    pub(crate) fn remove_redundant_states(&mut self) {
+    /// Объединение эквивалентных состояний попарным сравнением состояний внутри классов.
+    /// 
+    /// `minify` использует алгоритм Хопкрофта, эта реализация оставлена в тестах для сравнения результатов и скорости.
+    #[cfg(test)]
+    pub(crate) fn remove_redundant_states(&mut self) 
+        where State: FromStateId
+    {
         if self.has_unreachable_states() { return; }
 
+        // Разбиение строится для полной таблицы переходов, чтобы отсутствующие переходы
+        // обрабатывались так же, как переходы в тупиковое состояние
+        let trap_state = self.complete();
+
         let mut list = vec![];
 
-        let mut state_to_list_idx = HashMap::<char, usize>::new();
-        let mut state_to_transitions = HashMap::<(char, char), &Vec<char>>::new();
+        let mut state_to_list_idx = HashMap::<State, usize>::new();
+        let mut state_to_transitions = HashMap::<(State, Input), &Vec<State>>::new();
 
         // Лист не заканчивающих состояний
         list.push(self.states.iter()
             .filter(|state| !self.closing_states.contains(state))
             .map(|state| { 
-                state_to_list_idx.insert(*state, list.len()); 
+                state_to_list_idx.insert(state.clone(), list.len()); 
 
                 self.transitions.iter()
                     .filter(|((in_state, _), _)| in_state == state)
                     .for_each(|((_, input), out_state)| {
-                        state_to_transitions.insert((*state, *input), out_state);
+                        state_to_transitions.insert((state.clone(), input.clone()), out_state);
                     });
 
-                *state
+                state.clone()
             })
-            .collect::<Vec<char>>());
+            .collect::<Vec<State>>());
 
         // Лист заканчивающих состояний
         list.push(self.states.iter()
             .filter(|state| self.closing_states.contains(state))
             .map(|state| { 
-                state_to_list_idx.insert(*state, list.len()); 
+                state_to_list_idx.insert(state.clone(), list.len()); 
 
                 self.transitions.iter()
                     .filter(|((in_state, _), _)| in_state == state)
                     .for_each(|((_, input), out_state)| {
-                        state_to_transitions.insert((*state, *input), out_state);
+                        state_to_transitions.insert((state.clone(), input.clone()), out_state);
                     });
 
-                *state
+                state.clone()
             })
-            .collect::<Vec<char>>());
+            .collect::<Vec<State>>());
 
         let is_equivalent = |
-            s1: char, 
-            s2: char, 
-            state_to_transitions: &HashMap<(char, char), &Vec<char>>, 
-            state_to_list_idx: &HashMap<char, usize>
+            s1: &State, 
+            s2: &State, 
+            state_to_transitions: &HashMap<(State, Input), &Vec<State>>, 
+            state_to_list_idx: &HashMap<State, usize>
         | -> bool {
             self.inputs.iter().all(|input| {
-                let s1_transitions = state_to_transitions.get(&(s1, *input));
-                let s2_transitions = state_to_transitions.get(&(s2, *input));
+                let s1_transitions = state_to_transitions.get(&(s1.clone(), input.clone()));
+                let s2_transitions = state_to_transitions.get(&(s2.clone(), input.clone()));
 
                 match (s1_transitions, s2_transitions) {
                     (Some(s1_transitions), Some(s2_transitions)) => {
-                        let [s1_out, s2_out] = [s1_transitions[0], s2_transitions[0]];
-                        let s1_out_idx = state_to_list_idx.get(&s1_out);
-                        let s2_out_idx = state_to_list_idx.get(&s2_out);
+                        let [s1_out, s2_out] = [&s1_transitions[0], &s2_transitions[0]];
+                        let s1_out_idx = state_to_list_idx.get(s1_out);
+                        let s2_out_idx = state_to_list_idx.get(s2_out);
 
                         if !((s1_out == s2_out) || (s1_out_idx.unwrap() == s2_out_idx.unwrap())) {
                             return false;
@@ -297,7 +397,7 @@ impl Dfa<char, char> {
         };
 
         loop {
-            let mut new_list: Vec<Vec<char>> = vec![];
+            let mut new_list: Vec<Vec<State>> = vec![];
 
             for sublist in &list {
                 if sublist.len() == 1 {
@@ -305,24 +405,20 @@ impl Dfa<char, char> {
                     continue;
                 }
 
-                let mut sublist_windows = sublist.windows(2);
-                while let Some(states) = sublist_windows.next() {
-                    let [s1, s2] = [states[0], states[1]];
+                for states in sublist.windows(2) {
+                    let [s1, s2] = [&states[0], &states[1]];
 
                     if is_equivalent(s1, s2, &state_to_transitions, &state_to_list_idx) {
-                        match new_list.iter().position(|sublist| sublist.contains(&s1)) {
+                        match new_list.iter().position(|sublist| sublist.contains(s1)) {
                             Some(s1_idx) => {
-                                match new_list.iter().position(|sublist| sublist.contains(&s2)) {
-                                    Some(s2_idx) => {
-                                        new_list.remove(s2_idx);
-                                    },
-                                    None => {}
+                                if let Some(s2_idx) = new_list.iter().position(|sublist| sublist.contains(s2)) {
+                                    new_list.remove(s2_idx);
                                 }
 
-                                new_list[s1_idx].push(s2)
+                                new_list[s1_idx].push(s2.clone())
                             },
                             None => {
-                                new_list.push(vec![s1, s2]);
+                                new_list.push(vec![s1.clone(), s2.clone()]);
                             }
                         }
                     } else {
@@ -331,38 +427,28 @@ impl Dfa<char, char> {
 
                         new_list.iter_mut()
                             .filter(|new_sublist| {
-                                !(new_sublist.contains(&s1) || new_sublist.contains(&s2))
+                                !(new_sublist.contains(s1) || new_sublist.contains(s2))
                             })
                             .for_each(|sublist| {
-                                if !sublist.contains(&s1) && !is_s1_used &&
-                                    is_equivalent(sublist[0], s1, &state_to_transitions, &state_to_list_idx) 
+                                if !sublist.contains(s1) && !is_s1_used &&
+                                    is_equivalent(&sublist[0], s1, &state_to_transitions, &state_to_list_idx) 
                                 {
-                                    sublist.push(s1);
+                                    sublist.push(s1.clone());
                                     is_s1_used = true;
-                                } else if !sublist.contains(&s2) && !is_s2_used &&
-                                    is_equivalent(sublist[0], s2, &state_to_transitions, &state_to_list_idx) 
+                                } else if !sublist.contains(s2) && !is_s2_used &&
+                                    is_equivalent(&sublist[0], s2, &state_to_transitions, &state_to_list_idx) 
                                 {
-                                    sublist.push(s2);
+                                    sublist.push(s2.clone());
                                     is_s2_used = true;
                                 }
                             });
 
-                        if !is_s1_used {
-                            match new_list.iter().position(|sublist| sublist.contains(&s1)) {
-                                Some(_) => {},
-                                None => {
-                                    new_list.push(vec![s1]);
-                                }
-                            }
+                        if !is_s1_used && !new_list.iter().any(|sublist| sublist.contains(s1)) {
+                            new_list.push(vec![s1.clone()]);
                         }
 
-                        if !is_s2_used {
-                            match new_list.iter().position(|sublist| sublist.contains(&s2)) {
-                                Some(_) => {},
-                                None => {
-                                    new_list.push(vec![s2]);
-                                }
-                            }
+                        if !is_s2_used && !new_list.iter().any(|sublist| sublist.contains(s2)) {
+                            new_list.push(vec![s2.clone()]);
                         }
                     }
                 }
@@ -377,56 +463,62 @@ impl Dfa<char, char> {
 
                 for (idx, sublist) in list.iter().enumerate() {
                     for state in sublist {
-                        state_to_list_idx.insert(*state, idx); 
+                        state_to_list_idx.insert(state.clone(), idx); 
                     }
                 }
             }
         }
 
+        // Убрать класс тупиковых состояний, если тупиковое состояние было добавлено при дополнении таблицы
+        let mut dead_states = vec![];
+
+        if let Some(trap_state) = &trap_state {
+            let idx = list.iter()
+                .position(|sublist| sublist.contains(trap_state))
+                .expect("Trap state should belong to some class");
+
+            if !list[idx].iter().any(|state| self.starting_states.contains(state)) {
+                dead_states = list.remove(idx);
+
+                self.states.retain(|state| !dead_states.contains(state));
+            }
+        }
+
         let mut new_states = vec![];
         let mut new_starting_states = vec![];
         let mut new_closing_states = vec![];
 
+        let mut allocator = StateAllocator::new(
+            self.states.iter().chain(self.state_to_state_combo_map.keys()).cloned()
+        );
+
         list.iter().for_each(|sublist| {
             if sublist.len() > 1 {
-                let new_state = ('A'..='Z').into_iter()
-                    .filter(|ch| !new_states.contains(ch) && !self.states.contains(ch))
-                    .next()
-                    .expect("Not enough capital letters for states");
+                let new_state = allocator.allocate();
 
-                new_states.push(new_state);
+                new_states.push(new_state.clone());
 
                 for state in sublist {
-                    match self.closing_states.iter().position(|closing_state| closing_state == state) {
-                        Some(_) => {
-                            if !new_closing_states.contains(&new_state) {
-                                new_closing_states.push(new_state);
-                            }
-                        },
-                        _ => {}
-                    };
-
-                    match self.starting_states.iter().position(|closing_state| closing_state == state) {
-                        Some(_) => {
-                            if !new_starting_states.contains(&new_state) {
-                                new_starting_states.push(new_state);
-                            }
-                        },
-                        _ => {}
-                    };
+                    if self.closing_states.contains(state) && !new_closing_states.contains(&new_state) {
+                        new_closing_states.push(new_state.clone());
+                    }
+
+                    if self.starting_states.contains(state) && !new_starting_states.contains(&new_state) {
+                        new_starting_states.push(new_state.clone());
+                    }
                 }
 
-                self.state_combo_to_state_map.insert(sublist.to_vec(), new_state);
+                self.state_combo_to_state_map.insert(sublist.to_vec(), new_state.clone());
                 self.state_to_state_combo_map.insert(new_state, sublist.to_vec());
             } else {
-                new_states.push(sublist[0]);
+                new_states.push(sublist[0].clone());
 
                 if self.starting_states.contains(&sublist[0]) {
-                    new_starting_states.push(sublist[0]);
+                    new_starting_states.push(sublist[0].clone());
                 }
 
                 if self.closing_states.contains(&sublist[0]) {
-                    new_closing_states.push(sublist[0]);
+                    new_closing_states.push(sublist[0].clone());
                 }
             }
         });
@@ -436,22 +528,25 @@ impl Dfa<char, char> {
 
         // Создать новую таблицу
         self.transitions.iter()
+            .filter(|((in_state, _), out_state)| {
+                !dead_states.contains(in_state) && !dead_states.contains(&out_state[0])
+            })
             .for_each(|((in_state, input), out_state)| {
                 let new_in_state = match self.state_combo_to_state_map.iter()
-                    .filter(|(combo, _)| combo.contains(in_state)).nth(0)
+                    .find(|(combo, _)| combo.contains(in_state))
                 {
                     Some((_, state)) => state,
                     None => in_state
                 };
 
                 let new_out_state = match self.state_combo_to_state_map.iter()
-                    .filter(|(combo, _)| combo.contains(&out_state[0])).nth(0)
+                    .find(|(combo, _)| combo.contains(&out_state[0]))
                 {
                     Some((_, state)) => state,
                     None => &out_state[0]
                 };
 
-                table.insert((*new_in_state, *input), vec![*new_out_state]);
+                table.insert((new_in_state.clone(), input.clone()), vec![new_out_state.clone()]);
             });
 
         self.transitions = table;
@@ -460,11 +555,46 @@ impl Dfa<char, char> {
         self.closing_states = new_closing_states;
     }
 
-    pub fn minify(&mut self) {
+    pub fn minify(&mut self) 
+        where State: FromStateId
+    {
         if self.has_unreachable_states() {
             self.remove_unreachable_states();
         }
 
-        self.remove_redundant_states();
+        self.remove_redundant_states_hopcroft();
+    }
+
+    /// Минимизация алгоритмом Бжозовского: det(rev(det(rev(M)))).
+    ///
+    /// Детерминизация обращённого автомата с последующим удалением недостижимых состояний
+    /// даёт минимальный ДКА для обращённого языка, поэтому двойное обращение даёт
+    /// минимальный ДКА исходного языка. Результат совпадает с `minify` с точностью до имён состояний.
+    ///
+    /// Состояния результата выделяются из состояний промежуточного автомата, поэтому
+    /// `state_combo_to_state_map` и `state_to_state_combo_map` очищаются.
+    pub fn minify_brzozowski(&mut self)
+        where State: FromStateId
+    {
+        let reverse_determinize = |dfa: Self| {
+            let mut dfa = dfa.into_nfa().reverse().to_deterministic();
+            dfa.remove_unreachable_states();
+
+            dfa
+        };
+
+        let mut dfa = reverse_determinize(reverse_determinize(self.clone()));
+
+        // Для пустого языка обращённый автомат не имеет начальных состояний,
+        // но, как и в `minify`, начальное состояние исходного автомата остаётся
+        if dfa.starting_states.is_empty() {
+            dfa.states = self.starting_states.clone();
+            dfa.starting_states = self.starting_states.clone();
+        }
+
+        dfa.state_combo_to_state_map.clear();
+        dfa.state_to_state_combo_map.clear();
+
+        *self = dfa;
     }
 }
\ No newline at end of file
@@ -0,0 +1,109 @@
+use std::{collections::{HashMap, VecDeque}, hash::Hash};
+
+use crate::grammar::{Grammar, Symbol};
+
+use super::{Dfa, FiniteAutomataError, FromStateId, Nfa};
+
+/// Пара состояний двух автоматов, `None` - неявное тупиковое состояние.
+type StatePair<State, OtherState> = (Option<State>, Option<OtherState>);
+
+impl<State, Input> Dfa<State, Input> 
+    where State: Eq + Hash + Ord + Clone, Input: Eq + Hash + Clone
+{
+    /// Проверка эквивалентности языков двух автоматов.
+    /// 
+    /// Обход в ширину пар состояний (q1, q2), отсутствующий переход ведёт в неявное тупиковое состояние.
+    /// Если языки различаются, возвращается кратчайшая цепочка, которую допускает ровно один из автоматов.
+    pub fn equivalent<OtherState>(&self, other: &Dfa<OtherState, Input>) -> Result<(), Vec<Input>> 
+        where OtherState: Eq + Hash + Ord + Clone
+    {
+        let mut inputs = self.inputs.clone();
+
+        other.inputs.iter()
+            .for_each(|input| {
+                if !inputs.contains(input) {
+                    inputs.push(input.clone());
+                }
+            });
+
+        let is_accepting = |pair: &StatePair<State, OtherState>| -> (bool, bool) {
+            (
+                pair.0.as_ref().is_some_and(|state| self.closing_states.contains(state)),
+                pair.1.as_ref().is_some_and(|state| other.closing_states.contains(state))
+            )
+        };
+
+        let starting_pair = (self.starting_states.first().cloned(), other.starting_states.first().cloned());
+
+        // Пара -> (предыдущая пара, прочитанный символ)
+        let mut parents = HashMap::<StatePair<State, OtherState>, Option<(StatePair<State, OtherState>, Input)>>::new();
+        parents.insert(starting_pair.clone(), None);
+
+        let mut pairs_to_process = VecDeque::from([starting_pair]);
+
+        while let Some(pair) = pairs_to_process.pop_front() {
+            let (left, right) = is_accepting(&pair);
+
+            if left != right {
+                let mut word = vec![];
+                let mut current = pair;
+
+                while let Some(Some((parent, input))) = parents.get(&current) {
+                    word.push(input.clone());
+                    current = parent.clone();
+                }
+
+                word.reverse();
+
+                return Err(word);
+            }
+
+            for input in &inputs {
+                let out_pair = (
+                    pair.0.as_ref()
+                        .and_then(|state| self.transitions.get(&(state.clone(), input.clone())))
+                        .and_then(|out_state| out_state.first().cloned()),
+                    pair.1.as_ref()
+                        .and_then(|state| other.transitions.get(&(state.clone(), input.clone())))
+                        .and_then(|out_state| out_state.first().cloned())
+                );
+
+                // Из пары тупиковых состояний различающая цепочка не найдётся
+                if out_pair == (None, None) || parents.contains_key(&out_pair) { continue; }
+
+                parents.insert(out_pair.clone(), Some((pair.clone(), input.clone())));
+                pairs_to_process.push_back(out_pair);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<State, Input> Nfa<State, Input> 
+    where State: FromStateId + Eq + Hash + Ord + Clone, Input: Eq + Hash + Clone
+{
+    /// Проверка эквивалентности языков двух автоматов через их детерминизацию.
+    pub fn equivalent<OtherState>(&self, other: &Nfa<OtherState, Input>) -> Result<(), Vec<Input>> 
+        where OtherState: FromStateId + Eq + Hash + Ord + Clone
+    {
+        self.clone().to_deterministic()
+            .equivalent(&other.clone().to_deterministic())
+    }
+}
+
+impl Grammar {
+    /// Проверка эквивалентности языков двух регулярных грамматик через построенные по ним автоматы.
+    /// 
+    /// Внешняя ошибка означает, что хотя бы одна из грамматик не может быть преобразована в автомат,
+    /// внутренняя - содержит кратчайшую цепочку, выводимую ровно в одной из грамматик.
+    pub fn equivalent(&self, other: &Grammar) -> Result<Result<(), Vec<Symbol>>, FiniteAutomataError> {
+        let dfa = Dfa::<String, String>::try_from(self.clone())?;
+        let other_dfa = Dfa::<String, String>::try_from(other.clone())?;
+
+        Ok(
+            dfa.equivalent(&other_dfa)
+                .map_err(|word| word.into_iter().map(Symbol::terminal).collect())
+        )
+    }
+}
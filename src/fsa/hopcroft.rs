@@ -0,0 +1,185 @@
+use std::{collections::{HashMap, HashSet}, hash::Hash};
+
+use super::{Dfa, FromStateId, StateAllocator, StateTransitionTable};
+
+impl<State, Input> Dfa<State, Input> 
+    where State: FromStateId + Eq + Hash + Ord + Clone, Input: Eq + Hash + Clone
+{
+    /// Объединение эквивалентных состояний алгоритмом Хопкрофта за O(n·k·log n),
+    /// где n - число состояний, k - число входных символов.
+    /// 
+    /// Как и `remove_redundant_states`, сохраняет имена состояний, оставшихся в одиночных классах,
+    /// а для классов из нескольких состояний выделяет новые состояния и записывает их
+    /// в `state_combo_to_state_map`. Тупиковые состояния, появившиеся при дополнении таблицы переходов,
+    /// в результат не попадают.
+    pub(crate) fn remove_redundant_states_hopcroft(&mut self) {
+        if self.has_unreachable_states() { return; }
+
+        let trap_state = self.complete();
+
+        let state_to_idx = self.states.iter()
+            .enumerate()
+            .map(|(idx, state)| (state.clone(), idx))
+            .collect::<HashMap<State, usize>>();
+
+        // Таблица переходов и обратная к ней: symbol -> state -> предшественники
+        let mut delta = vec![vec![0; self.inputs.len()]; self.states.len()];
+        let mut inverse_delta = vec![vec![vec![]; self.states.len()]; self.inputs.len()];
+
+        for (state_idx, state) in self.states.iter().enumerate() {
+            for (input_idx, input) in self.inputs.iter().enumerate() {
+                let out_state = &self.transitions.get(&(state.clone(), input.clone()))
+                    .expect("Complete DFA should have every transition")[0];
+                let out_idx = state_to_idx[out_state];
+
+                delta[state_idx][input_idx] = out_idx;
+                inverse_delta[input_idx][out_idx].push(state_idx);
+            }
+        }
+
+        let closing_states = self.closing_states.iter().collect::<HashSet<&State>>();
+
+        // Начальное разбиение: заключительные и незаключительные состояния
+        let (closing, non_closing): (Vec<usize>, Vec<usize>) = (0..self.states.len())
+            .partition(|idx| closing_states.contains(&self.states[*idx]));
+
+        let mut blocks = [closing, non_closing].into_iter()
+            .filter(|block| !block.is_empty())
+            .collect::<Vec<Vec<usize>>>();
+
+        let mut block_of = vec![0; self.states.len()];
+
+        for (block_idx, block) in blocks.iter().enumerate() {
+            for state_idx in block {
+                block_of[*state_idx] = block_idx;
+            }
+        }
+
+        // Очередь пар (класс, символ), по которым ещё нужно разбивать классы
+        let mut worklist = vec![];
+
+        let smallest_block = (0..blocks.len()).min_by_key(|idx| blocks[*idx].len());
+
+        if let Some(block_idx) = smallest_block {
+            worklist.extend((0..self.inputs.len()).map(|input_idx| (block_idx, input_idx)));
+        }
+
+        while let Some((splitter_idx, input_idx)) = worklist.pop() {
+            // Состояния, переходящие по символу в класс-разделитель, сгруппированные по своим классам
+            let mut touched_blocks = HashMap::<usize, Vec<usize>>::new();
+
+            for state_idx in &blocks[splitter_idx] {
+                for pred_idx in &inverse_delta[input_idx][*state_idx] {
+                    touched_blocks.entry(block_of[*pred_idx]).or_default().push(*pred_idx);
+                }
+            }
+
+            let mut touched_blocks = touched_blocks.into_iter().collect::<Vec<(usize, Vec<usize>)>>();
+            touched_blocks.sort();
+
+            for (block_idx, mut touched) in touched_blocks {
+                touched.sort();
+                touched.dedup();
+
+                if touched.len() == blocks[block_idx].len() { continue; }
+
+                let (mut new_block, mut rest): (Vec<usize>, Vec<usize>) = blocks[block_idx].iter()
+                    .partition(|state_idx| touched.binary_search(state_idx).is_ok());
+
+                // Новый класс всегда меньший из двух, поэтому его достаточно добавить в очередь:
+                // если пара (класс, символ) уже была в очереди, она продолжает указывать на оставшуюся часть
+                if new_block.len() > rest.len() {
+                    std::mem::swap(&mut new_block, &mut rest);
+                }
+
+                let new_block_idx = blocks.len();
+
+                for state_idx in &new_block {
+                    block_of[*state_idx] = new_block_idx;
+                }
+
+                blocks[block_idx] = rest;
+                blocks.push(new_block);
+
+                worklist.extend((0..self.inputs.len()).map(|input_idx| (new_block_idx, input_idx)));
+            }
+        }
+
+        // Убрать класс тупиковых состояний, если тупиковое состояние было добавлено при дополнении таблицы
+        let dead_block_idx = trap_state
+            .map(|trap_state| block_of[state_to_idx[&trap_state]])
+            .filter(|block_idx| {
+                !blocks[*block_idx].iter().any(|state_idx| self.starting_states.contains(&self.states[*state_idx]))
+            });
+
+        let mut allocator = StateAllocator::new(
+            self.states.iter()
+                .enumerate()
+                .filter(|(state_idx, _)| Some(block_of[*state_idx]) != dead_block_idx)
+                .map(|(_, state)| state)
+                .chain(self.state_to_state_combo_map.keys())
+                .cloned()
+        );
+
+        // Классы в порядке первого вхождения их состояний в self.states
+        let mut block_to_state = HashMap::<usize, State>::new();
+        let mut ordered_blocks = vec![];
+
+        for block_idx in block_of.iter().copied() {
+            if Some(block_idx) == dead_block_idx || block_to_state.contains_key(&block_idx) { continue; }
+
+            let block = &blocks[block_idx];
+
+            let new_state = match block.as_slice() {
+                [state_idx] => self.states[*state_idx].clone(),
+                _ => {
+                    let mut combo = block.iter()
+                        .map(|state_idx| self.states[*state_idx].clone())
+                        .collect::<Vec<State>>();
+                    combo.sort();
+
+                    let new_state = allocator.allocate();
+
+                    self.state_combo_to_state_map.insert(combo.clone(), new_state.clone());
+                    self.state_to_state_combo_map.insert(new_state.clone(), combo);
+
+                    new_state
+                }
+            };
+
+            block_to_state.insert(block_idx, new_state);
+            ordered_blocks.push(block_idx);
+        }
+
+        let mut new_states = vec![];
+        let mut new_starting_states = vec![];
+        let mut new_closing_states = vec![];
+        let mut table = StateTransitionTable::new();
+
+        for block_idx in ordered_blocks {
+            let new_state = &block_to_state[&block_idx];
+            let block = &blocks[block_idx];
+
+            for (input_idx, input) in self.inputs.iter().enumerate() {
+                if let Some(out_state) = block_to_state.get(&block_of[delta[block[0]][input_idx]]) {
+                    table.insert((new_state.clone(), input.clone()), vec![out_state.clone()]);
+                }
+            }
+
+            if block.iter().any(|state_idx| self.starting_states.contains(&self.states[*state_idx])) {
+                new_starting_states.push(new_state.clone());
+            }
+
+            if closing_states.contains(&self.states[block[0]]) {
+                new_closing_states.push(new_state.clone());
+            }
+
+            new_states.push(new_state.clone());
+        }
+
+        self.transitions = table;
+        self.states = new_states;
+        self.starting_states = new_starting_states;
+        self.closing_states = new_closing_states;
+    }
+}
@@ -1,6 +1,6 @@
 #[macro_export]
 macro_rules! transitions {
     ($($in_state: literal, $input: literal -> $($out_state: literal),+);*) => {
-        crate::fsa::StateTransitionTable::from(vec![$((($in_state, $input), vec![$($out_state),*])),*])
+        $crate::fsa::StateTransitionTable::from(vec![$((($in_state, $input), vec![$($out_state),*])),*])
     };
 }
\ No newline at end of file
@@ -1,6 +1,9 @@
 mod state_transition_table;
 pub use state_transition_table::StateTransitionTable;
 
+mod state_allocator;
+pub use state_allocator::{FromStateId, StateAllocator};
+
 mod macros;
 
 mod nfa;
@@ -9,8 +12,20 @@ pub use nfa::Nfa;
 mod dfa;
 pub use dfa::Dfa;
 
+mod run;
+pub use run::{Run, RunStep};
+
+mod product;
+
+mod equivalence;
+
+mod hopcroft;
+mod canonical;
+
 use std::fmt::Display;
 
+use crate::grammar::Symbol;
+
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
 pub enum FSAType {
     #[default]
@@ -32,14 +47,18 @@ impl Display for FSAType {
 #[derive(Debug)]
 pub enum FiniteAutomataError {
     // Означает, что тип грамматики не подходит.
-    InavlidGrammarType
+    InavlidGrammarType,
+    // Означает, что символ грамматики нельзя представить состоянием или входным символом автомата.
+    InvalidSymbol(Symbol)
 }
 
 #[cfg(test)]
 mod test {
-    use crate::{fsa::{Dfa, Nfa}, generate, grammar::{GrammarType, RegularType}, rule, transitions};
+    use std::hash::Hash;
+
+    use crate::{fsa::{Dfa, Nfa}, generate, grammar::{GrammarType, RegularType, Symbol}, regex::Regex, rule, transitions};
 
-    use super::StateTransitionTable;
+    use super::{FiniteAutomataError, FromStateId, StateAllocator, StateTransitionTable};
 
     #[test]
     fn test_automata() {
@@ -73,6 +92,800 @@ mod test {
 
         println!("\n{}", nfa);
         println!("{}", nfa.transitions);
+
+        let grammar = generate!{
+            {'a'},
+            {'S'},
+            {
+                "S" -> "aS" | "a"
+            },
+            'S'
+        }.expect("Failed to generate grammar");
+
+        let dfa: Dfa<char, char> = grammar.try_into().expect("Failed to generate finite automata");
+        let expected_dfa = Regex::parse("a+").unwrap().to_nfa::<u32>().to_deterministic();
+
+        assert_eq!(dfa.equivalent(&expected_dfa), Ok(()), "S -> a should be kept together with S -> aS");
+    }
+
+    #[test]
+    fn test_left_regular_grammar() {
+        let grammar = generate!{
+            {'a', 'b'},
+            {'S', 'A'},
+            {
+                "S" -> "Sa" | "Ab",
+                "A" -> "Ab" | "a"
+            },
+            'S'
+        }.expect("Failed to generate grammar");
+
+        println!("{}", grammar);
+        println!("{}", grammar.grammar_type);
+
+        assert_eq!(grammar.grammar_type, GrammarType::Regular(RegularType::Left), "Expected left aligned regular grammar, got {}", grammar.grammar_type);
+
+        let nfa: Nfa<char, char> = grammar.clone().try_into().expect("Failed to generate finite automata");
+
+        println!("\n{}", nfa);
+        println!("{}", nfa.transitions);
+
+        for word in ["ab", "abbb", "abaa", "abbba"] {
+            assert!(nfa.accepts(&word.chars().collect::<Vec<char>>()), "NFA should accept {}", word);
+        }
+
+        for word in ["", "a", "b", "ba", "abab"] {
+            assert!(!nfa.accepts(&word.chars().collect::<Vec<char>>()), "NFA should not accept {}", word);
+        }
+
+        let dfa: Dfa<char, char> = grammar.try_into().expect("Failed to generate finite automata");
+        let expected_dfa = Regex::parse("ab+a*").unwrap().to_nfa::<u32>().to_deterministic();
+
+        println!("\n{}", dfa);
+        println!("{}", dfa.transitions);
+
+        assert_eq!(dfa.equivalent(&expected_dfa), Ok(()));
+
+        let grammar = generate!{
+            {'a', 'b'},
+            {'S'},
+            {
+                "S" -> "Sa" | "Sb" | "ε"
+            },
+            'S'
+        }.expect("Failed to generate grammar");
+
+        assert_eq!(grammar.grammar_type, GrammarType::Regular(RegularType::Left), "Expected left aligned regular grammar, got {}", grammar.grammar_type);
+
+        let dfa: Dfa<char, char> = grammar.try_into().expect("Failed to generate finite automata");
+        let expected_dfa = Regex::parse("(a|b)*").unwrap().to_nfa::<u32>().to_deterministic();
+
+        assert_eq!(dfa.equivalent(&expected_dfa), Ok(()));
+
+        // Грамматика со смешанным выравниванием не является регулярной
+        let grammar = generate!{
+            {'a'},
+            {'S', 'A', 'B'},
+            {
+                "S" -> "Aa",
+                "A" -> "aB",
+                "B" -> "a"
+            },
+            'S'
+        }.expect("Failed to generate grammar");
+
+        assert_eq!(grammar.grammar_type, GrammarType::ContextFree, "Expected context free grammar, got {}", grammar.grammar_type);
+
+        let result: Result<Nfa<char, char>, _> = grammar.try_into();
+
+        assert!(matches!(result, Err(FiniteAutomataError::InavlidGrammarType)));
+    }
+
+    #[test]
+    fn test_automata_to_grammar() {
+        for regex in ["(a|b)*abb", "(ab)*", "a|bc*", "ε", "∅"] {
+            let nfa = Regex::parse(regex).unwrap().to_nfa::<char>();
+
+            for regular_type in [RegularType::Right, RegularType::Left] {
+                let grammar = nfa.to_grammar(regular_type.clone()).expect("Failed to generate grammar");
+
+                println!("{}", grammar);
+                println!("{}", grammar.grammar_type);
+
+                assert_eq!(grammar.grammar_type, GrammarType::Regular(regular_type.clone()), "Unexpected grammar type for {}", regex);
+
+                let dfa: Dfa<char, char> = grammar.try_into().expect("Failed to generate finite automata");
+
+                assert_eq!(dfa.equivalent(&nfa.clone().to_deterministic()), Ok(()), "Round trip changed the language of {}", regex);
+            }
+        }
+
+        // Начальное состояние заключительное и в него есть переходы
+        let dfa = Regex::parse("(ab)*").unwrap().to_nfa::<char>().to_deterministic();
+        let grammar = dfa.to_grammar(RegularType::Right).expect("Failed to generate grammar");
+
+        println!("{}", grammar);
+
+        let empty_rule = grammar.rules.iter()
+            .find(|rule| rule.variants.contains(&vec![Symbol::Empty]))
+            .expect("Grammar should have S -> ε");
+
+        assert_eq!(empty_rule.input, vec![grammar.starting_non_terminal.clone()]);
+        assert!(
+            grammar.rules.iter()
+                .flat_map(|rule| &rule.variants)
+                .all(|variant| !variant.contains(&grammar.starting_non_terminal)),
+            "Starting non-terminal should not appear on the right side"
+        );
+
+        let transitions = transitions!{
+            'A','a' -> 'B';
+            'B','b' -> 'B'
+        };
+
+        let dfa = Dfa::new(
+            vec!['A', 'B'],
+            vec!['a', 'b'],
+            transitions,
+            vec!['A'],
+            vec!['B'],
+            Default::default(),
+            Default::default()
+        ).unwrap();
+
+        let grammar = dfa.to_grammar(RegularType::Left).expect("Failed to generate grammar");
+
+        println!("{}", grammar);
+
+        assert_eq!(grammar.starting_non_terminal, 'B');
+        assert_eq!(grammar.non_terminals, vec!['B']);
+        assert_eq!(grammar.rules.len(), 1);
+        assert_eq!(grammar.rules[0].variants, vec![vec!['a'], vec!['B', 'b']]);
+
+        // Из состояния B есть переход, но только в тупиковое состояние D
+        let transitions = transitions!{
+            'A','a' -> 'B';
+            'B','a' -> 'D';
+            'A','b' -> 'C'
+        };
+
+        let dfa = Dfa::new(
+            vec!['A', 'B', 'C', 'D'],
+            vec!['a', 'b'],
+            transitions,
+            vec!['A'],
+            vec!['C'],
+            Default::default(),
+            Default::default()
+        ).unwrap();
+
+        let grammar = dfa.to_grammar(RegularType::Right).expect("Failed to generate grammar");
+
+        println!("{}", grammar);
+
+        assert_eq!(grammar.non_terminals, vec!['A']);
+        assert_eq!(grammar.rules[0].variants, vec![vec!['b']]);
+
+        for regular_type in [RegularType::Right, RegularType::Left] {
+            let grammar = dfa.to_grammar(regular_type).expect("Failed to generate grammar");
+
+            let grammar_dfa: Dfa<char, char> = grammar.try_into().expect("Failed to generate finite automata");
+
+            assert_eq!(grammar_dfa.equivalent(&dfa), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_generic_automata() {
+        let transitions = transitions!{
+            0, b'a' -> 0, 1;
+            0, b'b' -> 0;
+            1, b'b' -> 2
+        };
+
+        let nfa = Nfa::<u32, u8>::new(
+            vec![0, 1, 2],
+            vec![b'a', b'b'],
+            transitions,
+            vec![0],
+            vec![2]
+        ).unwrap();
+
+        let dfa = nfa.to_deterministic();
+
+        println!("{}", dfa);
+        println!("{}", dfa.transitions);
+
+        assert_eq!(dfa.states, vec![0, 1, 2, 3, 4], "Expected new states for combos [0, 1] and [0, 2]");
+        assert_eq!(dfa.state_combo_to_state_map.get(&vec![0, 1]), Some(&3));
+        assert_eq!(dfa.state_combo_to_state_map.get(&vec![0, 2]), Some(&4));
+        assert_eq!(dfa.transitions.get(&(3, b'b')), Some(&vec![4]));
+        assert_eq!(dfa.closing_states, vec![2, 4]);
+
+        let transitions = transitions!{
+            "q0", "id" -> "q1";
+            "q1", "+" -> "q0"
+        };
+
+        let dfa = Dfa::new(
+            vec!["q0", "q1"],
+            vec!["id", "+"],
+            transitions,
+            vec!["q0"],
+            vec!["q1"],
+            Default::default(),
+            Default::default()
+        ).unwrap();
+
+        assert_eq!(dfa.to_string(), "M = { {q0, q1}, {id, +}, F, {q0}, {q1} }");
+    }
+
+    #[test]
+    fn test_subset_construction_without_state_limit() {
+        // Язык слов, в которых n-й символ с конца равен 'a', требует 2^n подмножеств
+        fn nth_from_end_nfa<State: FromStateId + Clone + Eq + Hash + Ord>(n: usize) -> Nfa<State, char> {
+            let states = (0..=n).map(State::from_state_id).collect::<Vec<State>>();
+
+            let mut transitions = StateTransitionTable::new();
+            transitions.insert((states[0].clone(), 'a'), vec![states[0].clone(), states[1].clone()]);
+            transitions.insert((states[0].clone(), 'b'), vec![states[0].clone()]);
+
+            for idx in 1..n {
+                transitions.insert((states[idx].clone(), 'a'), vec![states[idx + 1].clone()]);
+                transitions.insert((states[idx].clone(), 'b'), vec![states[idx + 1].clone()]);
+            }
+
+            let (starting_states, closing_states) = (vec![states[0].clone()], vec![states[n].clone()]);
+
+            Nfa::new(states, vec!['a', 'b'], transitions, starting_states, closing_states).unwrap()
+        }
+
+        let dfa = nth_from_end_nfa::<char>(6).to_deterministic();
+
+        assert!(dfa.state_combo_to_state_map.len() > 26, "Expected more subset states than capital letters");
+        assert!(dfa.state_combo_to_state_map.iter()
+            .all(|(combo, state)| dfa.state_to_state_combo_map.get(state) == Some(combo)));
+
+        let mut dfa = nth_from_end_nfa::<u32>(12).to_deterministic();
+        dfa.remove_unreachable_states();
+
+        assert_eq!(dfa.states.len(), 1 << 12, "Expected every reachable subset to become a state");
+    }
+
+    #[test]
+    fn test_state_allocator() {
+        let mut allocator = StateAllocator::<u32>::new([0, 2]);
+
+        assert_eq!(allocator.allocate(), 1);
+
+        let state = allocator.allocate_named("{0, 2}");
+
+        assert_eq!(state, 3);
+        assert_eq!(allocator.name(&state), Some("{0, 2}"));
+        assert_eq!(allocator.name(&1), None);
+
+        allocator.reserve(4);
+
+        assert_eq!(allocator.allocate_named("trap"), 5);
+        assert_eq!(allocator.name(&5), Some("trap"));
+
+        let mut allocator = StateAllocator::<char>::new(['A']);
+
+        assert_eq!(allocator.allocate_named("q0"), 'B');
+        assert_eq!(allocator.name(&'B'), Some("q0"));
+        assert_eq!((0..30).map(|_| allocator.allocate()).last(), Some('\u{0105}'));
+    }
+
+    #[test]
+    fn test_epsilon_transitions() {
+        // a*b*
+        let transitions = transitions!{
+            0, 'a' -> 0;
+            1, 'b' -> 1
+        };
+
+        let mut nfa = Nfa::<u32, char>::new(vec![0, 1], vec!['a', 'b'], transitions, vec![0], vec![1]).unwrap();
+        nfa.add_epsilon_transition(0, 1);
+
+        assert!(nfa.has_epsilon_transitions());
+        assert_eq!(nfa.epsilon_closure(&[0]), vec![0, 1]);
+        assert_eq!(nfa.epsilon_closure(&[1]), vec![1]);
+
+        let epsilon_free = nfa.clone().remove_epsilon_transitions();
+
+        println!("{}", epsilon_free);
+        println!("{}", epsilon_free.transitions);
+
+        assert!(!epsilon_free.has_epsilon_transitions());
+        assert_eq!(epsilon_free.closing_states, vec![0, 1]);
+        assert_eq!(epsilon_free.transitions.get(&(0, 'b')), Some(&vec![1]));
+
+        let dfa = nfa.to_deterministic();
+
+        println!("{}", dfa);
+        println!("{}", dfa.transitions);
+
+        let starting_state = dfa.starting_states[0];
+
+        assert_eq!(dfa.state_to_state_combo_map.get(&starting_state), Some(&vec![0, 1]));
+        assert_eq!(dfa.transitions.get(&(starting_state, 'a')), Some(&vec![starting_state]));
+        assert_eq!(dfa.transitions.get(&(starting_state, 'b')), Some(&vec![1]));
+        assert_eq!(dfa.transitions.get(&(1, 'a')), None);
+        assert!(!dfa.states.contains(&0), "State 0 has non-trivial closure and should not appear on its own");
+
+        let nfa = dfa.to_non_deterministic();
+
+        assert!(nfa.states.contains(&starting_state), "Combo state [0, 1] should be kept since state 0 is absent");
+        assert_eq!(nfa.transitions.get(&(starting_state, 'b')), Some(&vec![1]));
+    }
+
+    #[test]
+    fn test_run() {
+        let grammar = generate!{
+            {'a', 'b'},
+            {'S', 'A', 'B'},
+            {
+                "S" -> "aB" | "aA",
+                "B" -> "bB" | "a",
+                "A" -> "aA" | "b"
+            },
+            'S'
+        }.expect("Failed to generate grammar");
+
+        let nfa: Nfa<char, char> = grammar.try_into().expect("Failed to generate finite automata");
+
+        let run = nfa.run(&['a', 'b', 'b', 'a']);
+
+        println!("{}", run);
+
+        assert!(run.accepted);
+        assert_eq!(run.initial_states, vec!['S']);
+        assert_eq!(run.steps[0].states, vec!['A', 'B']);
+        assert_eq!(run.steps[0].fired_transitions, vec![('S', 'A'), ('S', 'B')]);
+        assert_eq!(run.steps[1].states, vec!['B', 'N']);
+
+        let run = nfa.run(&['a', 'b', 'a', 'a']);
+
+        println!("{}", run);
+
+        assert!(!run.accepted);
+        assert_eq!(run.died_at, Some(3));
+        assert_eq!(run.steps.len(), 4);
+
+        let dfa = nfa.clone().to_deterministic();
+
+        for word in ["aa", "ab", "aab", "abbba", "b", "a", "", "aba"] {
+            let word = word.chars().collect::<Vec<char>>();
+
+            assert_eq!(nfa.accepts(&word), dfa.accepts(&word), "NFA and DFA disagree on {:?}", word);
+        }
+
+        assert!(dfa.accepts(&['a', 'a']));
+        assert!(!dfa.accepts(&['b']));
+        assert!(!dfa.accepts(&[]));
+    }
+
+    #[test]
+    fn test_product_construction() {
+        fn words(alphabet: &[char], max_len: usize) -> Vec<Vec<char>> {
+            let mut words = vec![vec![]];
+            let mut last = vec![vec![]];
+
+            for _ in 0..max_len {
+                last = last.iter()
+                    .flat_map(|word: &Vec<char>| alphabet.iter().map(move |ch| [&word[..], &[*ch]].concat()))
+                    .collect();
+
+                words.extend(last.clone());
+            }
+
+            words
+        }
+
+        let mut contains_ab = Regex::parse("(a|b)*ab(a|b)*").unwrap().to_nfa::<u32>().to_deterministic();
+        contains_ab.minify();
+
+        let mut even_length = Regex::parse("((a|b)(a|b))*").unwrap().to_nfa::<u32>().to_deterministic();
+        even_length.minify();
+
+        let mut intersection = contains_ab.intersection(&even_length);
+
+        println!("{}", intersection);
+        println!("{}", intersection.transitions);
+
+        intersection.minify();
+
+        assert_eq!(intersection.states.len(), 6, "Expected 3 x 2 states after minimization");
+
+        let union = contains_ab.union(&even_length);
+        let difference = contains_ab.difference(&even_length);
+        let symmetric_difference = contains_ab.symmetric_difference(&even_length);
+
+        for word in words(&['a', 'b'], 6) {
+            let (left, right) = (contains_ab.accepts(&word), even_length.accepts(&word));
+
+            assert_eq!(intersection.accepts(&word), left && right, "Intersection failed on {:?}", word);
+            assert_eq!(union.accepts(&word), left || right, "Union failed on {:?}", word);
+            assert_eq!(difference.accepts(&word), left && !right, "Difference failed on {:?}", word);
+            assert_eq!(symmetric_difference.accepts(&word), left != right, "Symmetric difference failed on {:?}", word);
+        }
+
+        // Автоматы над разными алфавитами
+        let only_c = Regex::parse("c+").unwrap().to_nfa::<u32>().to_deterministic();
+        let union = contains_ab.union(&only_c);
+
+        assert_eq!(union.inputs, vec!['a', 'b', 'c']);
+
+        for word in words(&['a', 'b', 'c'], 4) {
+            assert_eq!(
+                union.accepts(&word), 
+                contains_ab.accepts(&word) || only_c.accepts(&word), 
+                "Union over different alphabets failed on {:?}", word
+            );
+        }
+    }
+
+    #[test]
+    fn test_complete_and_complement() {
+        let transitions = transitions!{
+            'A','a' -> 'B';
+            'A','b' -> 'C';
+            'B','a' -> 'D';
+            'D','a' -> 'D'
+        };
+
+        let dfa = Dfa::new(
+            vec!['A', 'B', 'C', 'D'], 
+            vec!['a', 'b'], 
+            transitions,
+            vec!['A'],
+            vec!['B', 'C'],
+            Default::default(),
+            Default::default()
+        ).unwrap();
+
+        let mut complete_dfa = dfa.clone();
+        let trap_state = complete_dfa.complete();
+
+        println!("{}", complete_dfa);
+        println!("{}", complete_dfa.transitions);
+
+        assert_eq!(trap_state, Some('E'));
+        assert_eq!(complete_dfa.transitions.len(), complete_dfa.states.len() * complete_dfa.inputs.len());
+        assert_eq!(complete_dfa.complete(), None, "Complete DFA should not get another trap state");
+
+        let complement = dfa.clone().complement();
+
+        for word in [&['a'][..], &['b'], &[], &['a', 'a'], &['b', 'a'], &['a', 'b', 'a']] {
+            assert_ne!(dfa.accepts(word), complement.accepts(word), "Complement failed on {:?}", word);
+        }
+
+        // Состояние C без переходов эквивалентно состоянию B с переходом в тупиковое состояние D
+        let mut minimal_dfa = dfa.clone();
+        minimal_dfa.minify();
+
+        println!("{}", minimal_dfa);
+        println!("{}", minimal_dfa.transitions);
+
+        assert_eq!(minimal_dfa.states, vec!['A', 'D']);
+        assert_eq!(minimal_dfa.transitions.len(), 2, "Dead states should not be kept after minimization");
+        assert!(minimal_dfa.accepts(&['a']) && minimal_dfa.accepts(&['b']) && !minimal_dfa.accepts(&['a', 'a']));
+    }
+
+    #[test]
+    fn test_equivalence() {
+        let grammar = generate!{
+            {'a', 'b'},
+            {'S', 'A', 'B'},
+            {
+                "S" -> "aB" | "aA",
+                "B" -> "bB" | "a",
+                "A" -> "aA" | "b"
+            },
+            'S'
+        }.expect("Failed to generate grammar");
+
+        let dfa: Dfa<char, char> = grammar.clone().try_into().expect("Failed to generate finite automata");
+        let reference = Regex::parse("ab*a|a+b").unwrap().to_nfa::<u32>().to_deterministic();
+
+        assert_eq!(dfa.equivalent(&reference), Ok(()));
+        assert_eq!(reference.equivalent(&dfa), Ok(()));
+
+        let wrong = Regex::parse("ab*a|a*b").unwrap().to_nfa::<u32>().to_deterministic();
+
+        assert_eq!(dfa.equivalent(&wrong), Err(vec!['b']), "Expected the shortest distinguishing word");
+
+        let nfa = Regex::parse("(a|b)*abb").unwrap().to_nfa::<u32>();
+        let other_nfa = Regex::parse("(a|b)*bb").unwrap().to_nfa::<char>();
+
+        assert_eq!(nfa.equivalent(&other_nfa), Err(vec!['b', 'b']));
+        assert_eq!(nfa.equivalent(&nfa), Ok(()));
+
+        let empty_word = Regex::parse("a*").unwrap().to_nfa::<u32>();
+        let non_empty = Regex::parse("a+").unwrap().to_nfa::<u32>();
+
+        assert_eq!(empty_word.equivalent(&non_empty), Err(vec![]));
+
+        let other_grammar = generate!{
+            {'a', 'b'},
+            {'S', 'A', 'B'},
+            {
+                "S" -> "aB" | "aA",
+                "B" -> "bB" | "a",
+                "A" -> "aA" | "a"
+            },
+            'S'
+        }.expect("Failed to generate grammar");
+
+        assert_eq!(grammar.equivalent(&grammar).expect("Both grammars are regular"), Ok(()));
+        assert_eq!(grammar.equivalent(&other_grammar).expect("Both grammars are regular"), Err(vec![Symbol::terminal('a'), Symbol::terminal('b')]));
+    }
+
+    #[test]
+    fn test_hopcroft_minimization() {
+        let transitions = transitions!{
+            'A','a' -> 'B';
+            'A','b' -> 'C';
+            'B','b' -> 'D';
+            'C','b' -> 'E';
+            'D','a' -> 'C';
+            'D','b' -> 'E';
+            'E','a' -> 'B';
+            'E','b' -> 'D'
+        };
+
+        let dfa = Dfa::new(
+            vec!['A', 'B', 'C', 'D', 'E'], 
+            vec!['a', 'b'], 
+            transitions,
+            vec!['A'],
+            vec!['D', 'E'],
+            Default::default(),
+            Default::default()
+        ).unwrap();
+
+        let mut hopcroft_dfa = dfa.clone();
+        hopcroft_dfa.minify();
+
+        println!("{}", hopcroft_dfa);
+        println!("{}", hopcroft_dfa.transitions);
+        println!("{:?}", hopcroft_dfa.state_combo_to_state_map);
+
+        let mut pairwise_dfa = dfa.clone();
+        pairwise_dfa.remove_redundant_states();
+
+        assert_eq!(hopcroft_dfa.states.len(), 3);
+        assert_eq!(hopcroft_dfa.states.len(), pairwise_dfa.states.len());
+        assert_eq!(hopcroft_dfa.equivalent(&pairwise_dfa), Ok(()));
+        assert_eq!(hopcroft_dfa.equivalent(&dfa), Ok(()));
+
+        let transitions = transitions!{
+            'A','a' -> 'B';
+            'A','b' -> 'B';
+            'B','b' -> 'C';
+            'C','a' -> 'B';
+            'C','b' -> 'C'
+        };
+
+        let minimal_dfa = Dfa::new(
+            vec!['A', 'B', 'C'], 
+            vec!['a', 'b'], 
+            transitions,
+            vec!['A'],
+            vec!['C'],
+            Default::default(),
+            Default::default()
+        ).unwrap();
+
+        assert!(hopcroft_dfa.is_isomorphic(&minimal_dfa), "Something went wrong with algorithm");
+
+        for regex in ["(a|b)*abb", "a(b|ε)c*", "((a|b)(a|b))*|c+"] {
+            let mut dfa = Regex::parse(regex).unwrap().to_nfa::<u32>().to_deterministic();
+            dfa.remove_unreachable_states();
+
+            let mut pairwise_dfa = dfa.clone();
+            pairwise_dfa.remove_redundant_states();
+
+            let mut hopcroft_dfa = dfa.clone();
+            hopcroft_dfa.minify();
+
+            assert_eq!(hopcroft_dfa.states.len(), pairwise_dfa.states.len(), "Different number of states for {}", regex);
+            assert_eq!(hopcroft_dfa.equivalent(&dfa), Ok(()), "Minimization changed the language of {}", regex);
+        }
+
+        for (regex, states_count) in [("(a|b)*a(a|b)", 4), ("(a|b)*a(a|b)(a|b)(a|b)", 16), ("(ab|ba)*a?", 3)] {
+            let dfa = Regex::parse(regex).unwrap().to_nfa::<u32>().to_deterministic();
+
+            let mut hopcroft_dfa = dfa.clone();
+            hopcroft_dfa.minify();
+
+            assert_eq!(hopcroft_dfa.states.len(), states_count, "Unexpected number of states for {}", regex);
+            assert_eq!(hopcroft_dfa.equivalent(&dfa), Ok(()), "Minimization changed the language of {}", regex);
+        }
+    }
+
+    /// Сравнение скорости минимизации: cargo test --release bench_minify -- --ignored --nocapture
+    #[test]
+    #[ignore]
+    fn bench_minify() {
+        use std::time::Instant;
+
+        // Счётчик по модулю n: переход по a прибавляет 1, по b - 2, заключительные состояния кратны 4.
+        // Минимальный автомат имеет 4 состояния.
+        for n in [64u32, 256, 1024, 4096] {
+            let mut transitions = StateTransitionTable::new();
+
+            for state in 0..n {
+                transitions.insert((state, 'a'), vec![(state + 1) % n]);
+                transitions.insert((state, 'b'), vec![(state + 2) % n]);
+            }
+
+            let dfa = Dfa::new(
+                (0..n).collect(),
+                vec!['a', 'b'],
+                transitions,
+                vec![0],
+                (0..n).filter(|state| state % 4 == 0).collect(),
+                Default::default(),
+                Default::default()
+            ).unwrap();
+
+            let mut pairwise_dfa = dfa.clone();
+            let start = Instant::now();
+            pairwise_dfa.remove_redundant_states();
+            let pairwise_time = start.elapsed();
+
+            let mut hopcroft_dfa = dfa.clone();
+            let start = Instant::now();
+            hopcroft_dfa.remove_redundant_states_hopcroft();
+            let hopcroft_time = start.elapsed();
+
+            println!(
+                "{} states -> {}: pairwise {:?}, hopcroft {:?}", 
+                n, hopcroft_dfa.states.len(), pairwise_time, hopcroft_time
+            );
+
+            assert_eq!(hopcroft_dfa.states.len(), 4);
+            assert_eq!(pairwise_dfa.states.len(), 4);
+        }
+    }
+
+    #[test]
+    fn test_reverse() {
+        let nfa = Regex::parse("ab*c|d").unwrap().to_nfa::<u32>();
+        let reversed_nfa = nfa.clone().reverse();
+
+        println!("{}", reversed_nfa);
+
+        for word in ["cba", "cbbba", "ca", "d"] {
+            assert!(reversed_nfa.accepts(&word.chars().collect::<Vec<char>>()), "Reversed NFA should accept {}", word);
+        }
+
+        for word in ["abc", "ac", "cb", ""] {
+            assert!(!reversed_nfa.accepts(&word.chars().collect::<Vec<char>>()), "Reversed NFA should not accept {}", word);
+        }
+
+        let expected_nfa = Regex::parse("cb*a|d").unwrap().to_nfa::<u32>();
+
+        assert_eq!(reversed_nfa.equivalent(&expected_nfa), Ok(()));
+        assert_eq!(reversed_nfa.reverse().equivalent(&nfa), Ok(()));
+    }
+
+    #[test]
+    fn test_brzozowski_minimization() {
+        let transitions = transitions!{
+            'A','a' -> 'B';
+            'A','b' -> 'C';
+            'B','b' -> 'D';
+            'C','b' -> 'E';
+            'D','a' -> 'C';
+            'D','b' -> 'E';
+            'E','a' -> 'B';
+            'E','b' -> 'D'
+        };
+
+        let dfa = Dfa::new(
+            vec!['A', 'B', 'C', 'D', 'E'], 
+            vec!['a', 'b'], 
+            transitions,
+            vec!['A'],
+            vec!['D', 'E'],
+            Default::default(),
+            Default::default()
+        ).unwrap();
+
+        let mut brzozowski_dfa = dfa.clone();
+        brzozowski_dfa.minify_brzozowski();
+
+        println!("{}", brzozowski_dfa);
+        println!("{}", brzozowski_dfa.transitions);
+
+        assert_eq!(brzozowski_dfa.states.len(), 3);
+        assert_eq!(brzozowski_dfa.equivalent(&dfa), Ok(()));
+
+        for regex in ["(a|b)*abb", "a(b|ε)c*", "(ab|ba)*a?", "((a|b)(a|b))*|c+", "(a|b)*a(a|b)(a|b)(a|b)", "∅"] {
+            let dfa = Regex::parse(regex).unwrap().to_nfa::<u32>().to_deterministic();
+
+            let mut hopcroft_dfa = dfa.clone();
+            hopcroft_dfa.minify();
+
+            let mut brzozowski_dfa = dfa.clone();
+            brzozowski_dfa.minify_brzozowski();
+
+            assert_eq!(brzozowski_dfa.states.len(), hopcroft_dfa.states.len(), "Different number of states for {}", regex);
+            assert_eq!(brzozowski_dfa.equivalent(&dfa), Ok(()), "Minimization changed the language of {}", regex);
+        }
+    }
+
+    #[test]
+    fn test_canonical_form() {
+        let dfa = Regex::parse("(a|b)*abb|c").unwrap().to_nfa::<u32>().to_deterministic();
+
+        let mut hopcroft_dfa = dfa.clone();
+        hopcroft_dfa.minify();
+
+        let mut brzozowski_dfa = dfa.clone();
+        brzozowski_dfa.minify_brzozowski();
+
+        assert!(hopcroft_dfa.is_isomorphic(&brzozowski_dfa), "Minimal DFAs should be isomorphic");
+
+        hopcroft_dfa.canonicalize();
+        brzozowski_dfa.canonicalize();
+
+        println!("{}", hopcroft_dfa);
+        println!("{}", hopcroft_dfa.transitions);
+
+        assert_eq!(hopcroft_dfa.states, vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(hopcroft_dfa.states, brzozowski_dfa.states);
+        assert_eq!(hopcroft_dfa.transitions, brzozowski_dfa.transitions);
+        assert_eq!(hopcroft_dfa.starting_states, brzozowski_dfa.starting_states);
+        assert_eq!(hopcroft_dfa.closing_states, brzozowski_dfa.closing_states);
+        assert!(hopcroft_dfa.state_to_state_combo_map.is_empty() && hopcroft_dfa.state_combo_to_state_map.is_empty());
+
+        let transitions = transitions!{
+            'X','a' -> 'Y';
+            'X','b' -> 'X';
+            'Y','a' -> 'Y';
+            'Y','b' -> 'Z';
+            'Z','a' -> 'Y';
+            'Z','b' -> 'X'
+        };
+
+        let ends_with_ab = Dfa::new(
+            vec!['X', 'Y', 'Z'],
+            vec!['b', 'a'],
+            transitions,
+            vec!['X'],
+            vec!['Z'],
+            Default::default(),
+            Default::default()
+        ).unwrap();
+
+        let mut canonical_dfa = ends_with_ab.clone();
+        canonical_dfa.canonicalize();
+
+        println!("{}", canonical_dfa);
+        println!("{}", canonical_dfa.transitions);
+
+        assert_eq!(canonical_dfa.states, vec!['A', 'B', 'C']);
+        assert_eq!(canonical_dfa.inputs, vec!['a', 'b']);
+        assert_eq!(canonical_dfa.transitions.get(&('A', 'a')), Some(&vec!['B']));
+        assert_eq!(canonical_dfa.transitions.get(&('B', 'b')), Some(&vec!['C']));
+        assert_eq!(canonical_dfa.closing_states, vec!['C']);
+        assert!(canonical_dfa.is_isomorphic(&ends_with_ab));
+
+        let mut other_dfa = ends_with_ab.clone();
+        other_dfa.closing_states = vec!['Y'];
+
+        assert!(!other_dfa.is_isomorphic(&ends_with_ab));
+
+        let mut regex_dfa = Regex::parse("(a|b)*ab").unwrap().to_nfa::<u32>().to_deterministic();
+
+        assert!(!regex_dfa.is_isomorphic(&ends_with_ab), "DFA with unreachable states is not isomorphic to the minimal one");
+
+        regex_dfa.minify();
+
+        assert!(regex_dfa.is_isomorphic(&ends_with_ab));
     }
 
     #[test]
@@ -141,17 +954,46 @@ mod test {
         
         println!("has unreachable states: {}", dfa.has_unreachable_states());
 
+        let original_dfa = dfa.clone();
+
         dfa.remove_redundant_states();
 
+        assert_eq!(dfa.equivalent(&original_dfa), Ok(()), "Minimization should preserve the language");
+
         println!("{}", dfa);
         println!("{}", dfa.transitions);
 
-        assert_eq!(dfa.states, vec!['A', 'F', 'G'], "Something went wrong with algorithm");
+        let transitions = transitions!{
+            'A','a' -> 'B';
+            'A','b' -> 'B';
+            'B','b' -> 'C';
+            'C','a' -> 'B';
+            'C','b' -> 'C'
+        };
+
+        let minimal_dfa = Dfa::new(
+            vec!['A', 'B', 'C'], 
+            vec!['a', 'b'], 
+            transitions,
+            vec!['A'],
+            vec!['C'],
+            Default::default(),
+            Default::default()
+        ).unwrap();
+
+        assert!(dfa.is_isomorphic(&minimal_dfa), "Something went wrong with algorithm");
+
+        let mut canonical_minimal_dfa = minimal_dfa.clone();
+        canonical_minimal_dfa.canonicalize();
+
+        dfa.canonicalize();
+
+        assert!(dfa == canonical_minimal_dfa, "Canonical forms of minimal DFAs should be equal");
     }
 
     #[test]
     fn test_transition() {
-        let rules = vec![
+        let rules = [
             rule! { "S" -> "aB" | "aA" },
             rule! { "B" -> "bB" | "aN" },
             rule! { "A" -> "aA" | "bN" }
@@ -163,14 +1005,14 @@ mod test {
             .for_each(|rule| {
                 rule.variants.iter()
                     .for_each(|variant| {
-                        let (arg, output) = (variant[0], variant[1]);
+                        let (arg, output) = (variant[0].clone(), variant[1].clone());
         
-                        let vec = match transitions.get_mut(&(rule.input[0], arg)) {
+                        let vec = match transitions.get_mut(&(rule.input[0].clone(), arg.clone())) {
                             Some(vec) => vec,
                             None => {
-                                transitions.insert((rule.input[0], arg), vec![]);
+                                transitions.insert((rule.input[0].clone(), arg.clone()), vec![]);
         
-                                transitions.get_mut(&(rule.input[0], arg)).unwrap()
+                                transitions.get_mut(&(rule.input[0].clone(), arg)).unwrap()
                             }
                         };
         
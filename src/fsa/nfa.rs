@@ -1,12 +1,13 @@
-use std::{collections::HashMap, fmt::Display, hash::Hash};
+use std::{collections::{HashMap, HashSet}, fmt::Display, hash::Hash, str::FromStr};
 
-use crate::grammar::{Grammar, GrammarType, RegularType};
+use crate::grammar::{Grammar, GrammarError, GrammarType, RegularType, Rule, Symbol};
 
-use super::{Dfa, FiniteAutomataError, StateTransitionTable};
+use super::{Dfa, FiniteAutomataError, FromStateId, StateAllocator, StateTransitionTable};
 
 /// Недетерминированный конечный автомат принимает вид
 /// M = (Q, T, F, H, Z), где 
 /// 
+/// ```text
 ///     Q - конечное множество состояний автомата;
 /// 
 ///     T - конечное множество допустимых входных символов;
@@ -16,36 +17,42 @@ use super::{Dfa, FiniteAutomataError, StateTransitionTable};
 ///     H - конечное множество начальных состояний автомата;
 /// 
 ///     Z - множество заключительных состояний автомата Z ⊆ (подмножество) Q.
+/// ```
+/// 
+/// Помимо переходов по входным символам, автомат может иметь ε-переходы,
+/// которые выполняются без чтения входного символа.
 #[derive(Clone, PartialEq, Eq)]
-pub struct Nfa<State: Eq + Hash, Input> {
+pub struct Nfa<State: Eq + Hash, Input: Eq + Hash> {
     pub states: Vec<State>,
     pub inputs: Vec<Input>,
-    pub transitions: StateTransitionTable,
+    pub transitions: StateTransitionTable<State, Input>,
+    /// State -> ε-переходы из этого состояния
+    pub epsilon_transitions: HashMap<State, Vec<State>>,
     pub starting_states: Vec<State>,
     pub closing_states: Vec<State>,
 }
 
-impl<State: Copy + Eq + Hash, Input: Copy> Display for Nfa<State, Input> 
-    where String: From<State> + From<Input> 
+impl<State, Input> Display for Nfa<State, Input> 
+    where State: Eq + Hash + Display, Input: Eq + Hash + Display
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let states = self.states.iter()
-            .map(|sym| String::from(*sym))
+            .map(|sym| sym.to_string())
             .collect::<Vec<String>>()
             .join(", ");
 
         let inputs = self.inputs.iter()
-            .map(|sym| String::from(*sym))
+            .map(|sym| sym.to_string())
             .collect::<Vec<String>>()
             .join(", ");
 
         let starting_states = self.starting_states.iter()
-            .map(|sym| String::from(*sym))
+            .map(|sym| sym.to_string())
             .collect::<Vec<String>>()
             .join(", ");
 
         let closing_states = self.closing_states.iter()
-            .map(|sym| String::from(*sym))
+            .map(|sym| sym.to_string())
             .collect::<Vec<String>>()
             .join(", ");
 
@@ -53,106 +60,272 @@ impl<State: Copy + Eq + Hash, Input: Copy> Display for Nfa<State, Input>
     }
 }
 
-impl TryFrom<Grammar> for Nfa<char, char> {
+impl<State, Input> TryFrom<Grammar> for Nfa<State, Input>
+    where 
+        State: FromStr + FromStateId + Eq + Hash + Ord + Clone, 
+        Input: FromStr + Eq + Hash + Clone
+{
     type Error = FiniteAutomataError;
 
+    /// Построение НКА по регулярной грамматике.
+    /// 
+    /// Состояния и входные символы получаются разбором имён нетерминалов и терминалов,
+    /// например, `Nfa<char, char>` строится только по грамматике с односимвольными именами.
+    /// 
+    /// Для грамматики, выровненной влево, правила A -> Ba заменяются на A -> aB:
+    /// полученная грамматика выровнена вправо и порождает обращённый язык,
+    /// поэтому построенный по ней автомат обращается.
     fn try_from(mut grammar: Grammar) -> Result<Self, Self::Error> {
-        if grammar.grammar_type != GrammarType::Regular(RegularType::Right) {
-            return Err(FiniteAutomataError::InavlidGrammarType);
+        match grammar.grammar_type {
+            GrammarType::Regular(RegularType::Right) => Self::from_right_regular_grammar(grammar),
+            GrammarType::Regular(RegularType::Left) => {
+                grammar.rules.iter_mut()
+                    .flat_map(|rule| rule.variants.iter_mut())
+                    .for_each(|variant| variant.reverse());
+
+                grammar.grammar_type = GrammarType::Regular(RegularType::Right);
+
+                Self::from_right_regular_grammar(grammar).map(Self::reverse)
+            },
+            _ => Err(FiniteAutomataError::InavlidGrammarType)
         }
+    }
+}
 
-        let closing_non_terminal = if grammar.non_terminals.contains(&'N') {
-            ('A'..='Z').into_iter()
-                .filter(|ch| !grammar.non_terminals.contains(ch))
-                .next()
-                .expect("Not enough capital letters for states")
-        } else {
-            'N'
+impl<State, Input> Nfa<State, Input> 
+    where State: FromStateId + Eq + Hash + Ord + Clone, Input: Eq + Hash + Clone
+{
+    /// Построение регулярной грамматики заданного вида, порождающей язык автомата.
+    ///
+    /// Для грамматики, выровненной вправо, нетерминалами становятся состояния автомата:
+    /// переход (A, a) -> B даёт правило A -> aB, а если B - заключительное состояние, то и A -> a.
+    /// Состояния, из которых не достижимо ни одно заключительное состояние, в нетерминалы не попадают.
+    /// Если начальное состояние заключительное, добавляется правило S -> ε. Чтобы S не встречался
+    /// в правых частях правил (и при нескольких начальных состояниях), вводится новый начальный нетерминал,
+    /// который получает правила всех начальных состояний.
+    ///
+    /// Грамматика, выровненная влево, строится по обращённому автомату, после чего правые части
+    /// правил A -> aB обращаются в A -> Ba.
+    pub fn to_grammar(&self, regular_type: RegularType) -> Result<Grammar, GrammarError> 
+        where State: Display, Input: Display
+    {
+        let nfa = self.clone().remove_epsilon_transitions();
+
+        let nfa = match regular_type {
+            RegularType::Right => nfa,
+            RegularType::Left => nfa.reverse()
         };
 
-        let mut states = grammar.non_terminals;
-        states.push(closing_non_terminal);
-
-        let inputs = grammar.terminals;
-        let starting_states = vec![grammar.starting_non_terminal];
-        let mut closing_states = vec![closing_non_terminal];
-
-        // Добавляем правила A -> bN, если существуют A -> b и нет A -> bB
-        grammar.rules.iter_mut()
-            .for_each(|rule| {
-                let variants = rule.variants.clone();
-                // println!("{variants:?}");
-
-                rule.variants.iter_mut()
-                    .for_each(|variant| { 
-                        if variant.len() == 1 {
-                            if variant[0] == 'ε' && rule.input[0] == grammar.starting_non_terminal {
-                                closing_states.push(grammar.starting_non_terminal);
-                            } else if !variants.iter().filter(|v| v.len() > 1).any(|v| v.starts_with(&variant[..])) {
-                                variant.push(closing_non_terminal);
-                            }
-                        }
+        let terminals = nfa.inputs.iter()
+            .map(|input| Symbol::terminal(input.to_string()))
+            .collect::<Vec<Symbol>>();
+
+        let non_terminal = |state: &State| Symbol::non_terminal(state.to_string());
+
+        let closing_states = nfa.closing_states.iter().collect::<HashSet<&State>>();
+
+        // Правила есть только у состояний, из которых по непустому слову достижимо заключительное состояние,
+        // поэтому A -> aB нужно только для таких B
+        let mut productive_states = HashSet::<&State>::new();
+
+        loop {
+            let new_productive_states = nfa.transitions.iter()
+                .filter(|((state, _), out_states)| {
+                    !productive_states.contains(state) &&
+                    out_states.iter().any(|out_state| closing_states.contains(out_state) || productive_states.contains(out_state))
+                })
+                .map(|((state, _), _)| state)
+                .collect::<Vec<&State>>();
+
+            if new_productive_states.is_empty() { break; }
+
+            productive_states.extend(new_productive_states);
+        }
+
+        let variants_of = |state: &State| -> Vec<Vec<Symbol>> {
+            let mut variants = vec![];
+
+            for (input, terminal) in nfa.inputs.iter().zip(&terminals) {
+                for out_state in nfa.transitions.get(&(state.clone(), input.clone())).into_iter().flatten() {
+                    if productive_states.contains(out_state) {
+                        variants.push(vec![terminal.clone(), non_terminal(out_state)]);
                     }
-                )
-            });
 
-        // Построим таблицу функции переходов
-        let mut transitions = StateTransitionTable::new();
+                    if closing_states.contains(out_state) && !variants.contains(&vec![terminal.clone()]) {
+                        variants.push(vec![terminal.clone()]);
+                    }
+                }
+            }
+
+            variants
+        };
+
+        let accepts_empty_word = nfa.starting_states.iter().any(|state| closing_states.contains(state));
+
+        let is_start_on_right_side = nfa.transitions.iter()
+            .any(|(_, out_states)| out_states.iter().any(|state| nfa.starting_states.contains(state)));
+
+        let mut non_terminals = nfa.states.iter().map(non_terminal).collect::<Vec<Symbol>>();
+        let mut rules = nfa.states.iter()
+            .map(|state| Rule { input: vec![non_terminal(state)], variants: variants_of(state) })
+            .collect::<Vec<Rule>>();
+
+        let starting_non_terminal = match nfa.starting_states.as_slice() {
+            [state] if !(accepts_empty_word && is_start_on_right_side) => {
+                let starting_non_terminal = non_terminal(state);
+
+                if accepts_empty_word {
+                    rules.iter_mut()
+                        .find(|rule| rule.input[0] == starting_non_terminal)
+                        .expect("Starting state should have a rule")
+                        .variants
+                        .push(vec![Symbol::Empty]);
+                }
+
+                starting_non_terminal
+            },
+            _ => {
+                let mut allocator = StateAllocator::new(nfa.states.iter().cloned());
+
+                // Имя нового нетерминала не должно совпадать с именами терминалов
+                let starting_non_terminal = loop {
+                    let symbol = non_terminal(&allocator.allocate());
+
+                    if !terminals.iter().any(|terminal| terminal.name() == symbol.name()) {
+                        break symbol;
+                    }
+                };
 
-        grammar.rules.iter()
-            .for_each(|rule| {
-                // let repeats = Vec::<(char, Vec<char>)>::new();
+                let mut variants = vec![];
 
-                rule.variants.iter()
+                nfa.starting_states.iter()
+                    .flat_map(variants_of)
                     .for_each(|variant| {
-                        println!("{:?} -> {:?}, {}, {}", rule.input, variant, variant.len(), variant[0] == 'ε');
-                        if variant.len() != 1 && variant[0] != 'ε' {
-                            println!("{variant:?}");
-                            let (arg, output) = (variant[0], variant[1]);
-            
-                            let vec = match transitions.get_mut(&(rule.input[0], arg)) {
-                                Some(vec) => vec,
-                                None => {
-                                    transitions.insert((rule.input[0], arg), vec![]);
-    
-                                    transitions.get_mut(&(rule.input[0], arg)).unwrap()
-                                }
-                            };
-    
-                            vec.push(output);
-    
-                            if vec.len() != 1 {
-                                vec.sort();
-                            }
+                        if !variants.contains(&variant) {
+                            variants.push(variant);
                         }
                     });
-            });
-
-        // let automata_type = if transitions.iter()
-        //     .any(|(_, state)| state.len() != 1)
-        // {
-        //     FiniteAutomataType::NonDeterministic
-        // } else {
-        //     FiniteAutomataType::Deterministic
-        // };
-        
-        Ok(Self {
-            states,
-            inputs,
-            transitions,
-            starting_states,
-            closing_states,
-        })
+
+                if accepts_empty_word {
+                    variants.push(vec![Symbol::Empty]);
+                }
+
+                non_terminals.insert(0, starting_non_terminal.clone());
+                rules.insert(0, Rule { input: vec![starting_non_terminal.clone()], variants });
+
+                starting_non_terminal
+            }
+        };
+
+        if regular_type == RegularType::Left {
+            rules.iter_mut()
+                .flat_map(|rule| rule.variants.iter_mut())
+                .for_each(|variant| variant.reverse());
+        }
+
+        rules.retain(|rule| !rule.variants.is_empty());
+        non_terminals.retain(|non_terminal| 
+            *non_terminal == starting_non_terminal || rules.iter().any(|rule| rule.input[0] == *non_terminal)
+        );
+
+        let mut grammar = Grammar::new(terminals, non_terminals, starting_non_terminal, rules)?;
+
+        // Грамматика, все правила которой имеют вид A -> a или S -> ε, подходит под оба вида
+        grammar.grammar_type = GrammarType::Regular(regular_type);
+
+        Ok(grammar)
+    }
+
+    /// Построение НКА по грамматике, выровненной вправо.
+    /// 
+    /// Нетерминалы становятся состояниями, к ним добавляется заключительное состояние N.
+    /// Правило A -> aB даёт переход (A, a) -> B, правило A -> a - переход (A, a) -> N,
+    /// правило A -> ε делает состояние A заключительным. Для правил с цепочкой терминалов
+    /// A -> a₁...aₖB выделяются промежуточные состояния.
+    fn from_right_regular_grammar(grammar: Grammar) -> Result<Self, FiniteAutomataError> 
+        where State: FromStr, Input: FromStr
+    {
+        let parse_state = |symbol: &Symbol| -> Result<State, FiniteAutomataError> {
+            symbol.name().parse().map_err(|_| FiniteAutomataError::InvalidSymbol(symbol.clone()))
+        };
+
+        let parse_input = |symbol: &Symbol| -> Result<Input, FiniteAutomataError> {
+            symbol.name().parse().map_err(|_| FiniteAutomataError::InvalidSymbol(symbol.clone()))
+        };
+
+        let mut states = grammar.non_terminals.iter()
+            .map(parse_state)
+            .collect::<Result<Vec<State>, FiniteAutomataError>>()?;
+
+        let inputs = grammar.terminals.iter()
+            .map(parse_input)
+            .collect::<Result<Vec<Input>, FiniteAutomataError>>()?;
+
+        let mut allocator = StateAllocator::new(states.iter().cloned());
+
+        let closing_state = match "N".parse::<State>() {
+            Ok(state) if !states.contains(&state) => {
+                allocator.reserve(state.clone());
+
+                state
+            },
+            _ => allocator.allocate()
+        };
+
+        states.push(closing_state.clone());
+
+        let starting_states = vec![parse_state(&grammar.starting_non_terminal)?];
+        let mut nfa = Self::new(states, inputs, StateTransitionTable::new(), starting_states, vec![closing_state.clone()])?;
+
+        for rule in &grammar.rules {
+            let in_state = parse_state(&rule.input[0])?;
+
+            for variant in &rule.variants {
+                if let [Symbol::Empty] = variant.as_slice() {
+                    if !nfa.closing_states.contains(&in_state) {
+                        nfa.closing_states.push(in_state.clone());
+                    }
+
+                    continue;
+                }
+
+                let (word, out_state) = match variant.split_last() {
+                    Some((last, word)) if last.is_non_terminal() => (word, parse_state(last)?),
+                    _ => (variant.as_slice(), closing_state.clone())
+                };
+
+                let mut state = in_state.clone();
+
+                for (idx, symbol) in word.iter().enumerate() {
+                    let next_state = if idx + 1 == word.len() {
+                        out_state.clone()
+                    } else {
+                        let next_state = allocator.allocate();
+                        nfa.states.push(next_state.clone());
+
+                        next_state
+                    };
+
+                    nfa.add_transition(state, parse_input(symbol)?, next_state.clone());
+
+                    state = next_state;
+                }
+            }
+        }
+
+        Ok(nfa)
     }
 }
 
-impl Nfa<char, char> {
+impl<State, Input> Nfa<State, Input> 
+    where State: Eq + Hash + Ord + Clone, Input: Eq + Hash + Clone
+{
     pub fn new(
-        states: Vec<char>, 
-        inputs: Vec<char>, 
-        transitions: StateTransitionTable, 
-        starting_states: Vec<char>, 
-        closing_states: Vec<char>
+        states: Vec<State>, 
+        inputs: Vec<Input>, 
+        transitions: StateTransitionTable<State, Input>, 
+        starting_states: Vec<State>, 
+        closing_states: Vec<State>
     ) -> Result<Self, FiniteAutomataError> {
         // check for invalid starting states, closing states, transitions
 
@@ -160,137 +333,244 @@ impl Nfa<char, char> {
             states,
             inputs,
             transitions,
+            epsilon_transitions: HashMap::new(),
             starting_states,
             closing_states
         })
     }
 
-    pub fn to_deterministic(mut self) -> Dfa<char, char> {
-        let mut state_combo_to_state_map = HashMap::new();
-        let mut state_to_state_combo_map = HashMap::new();
+    pub fn add_transition(&mut self, in_state: State, input: Input, out_state: State) {
+        let key = (in_state, input);
 
-        let mut states_to_process = Vec::<Vec<char>>::new();
-
-        // Обработать существующие недетерминированные состояния
-        self.transitions.iter_mut()
-            .filter(|(_, state)| state.len() != 1)
-            .for_each(|(_, state)| {
-                if state.len() > 0 {
-                    if !state_combo_to_state_map.contains_key(&state.to_vec()) {
-                        let new_state = ('A'..='Z').into_iter()
-                            .filter(|ch| !self.states.contains(ch))
-                            .next()
-                            .expect("Not enough capital letters for states");
-    
-                        // println!("!!! {state:?} to {new_state}");
-    
-                        if self.closing_states.iter()
-                            .any(|closing_state| 
-                                state.contains(closing_state) && !self.closing_states.contains(&new_state)
-                            )
-                        {
-                            self.closing_states.push(new_state);
-                        }
-    
-                        state_combo_to_state_map.insert(state.to_vec(), new_state);
-                        state_to_state_combo_map.insert(new_state, state.to_vec());
-    
-                        self.states.push(new_state);
-    
-                        states_to_process.push(state.to_vec());
-    
-                        *state = vec![new_state];
-                    } else {
-                        *state = vec![state_combo_to_state_map.get(&state.to_vec()).unwrap().clone()];
-                    }
+        match self.transitions.get_mut(&key) {
+            Some(out_states) => {
+                if !out_states.contains(&out_state) {
+                    out_states.push(out_state);
+                    out_states.sort();
                 }
-            });
+            },
+            None => {
+                self.transitions.insert(key, vec![out_state]);
+            }
+        }
+    }
+
+    pub fn add_epsilon_transition(&mut self, in_state: State, out_state: State) {
+        let out_states = self.epsilon_transitions.entry(in_state).or_default();
+
+        if !out_states.contains(&out_state) {
+            out_states.push(out_state);
+            out_states.sort();
+        }
+    }
+
+    pub fn has_epsilon_transitions(&self) -> bool {
+        self.epsilon_transitions.values().any(|out_states| !out_states.is_empty())
+    }
+
+    /// ε-замыкание множества состояний: все состояния, достижимые из `states`
+    /// только по ε-переходам (включая сами состояния `states`).
+    pub fn epsilon_closure(&self, states: &[State]) -> Vec<State> {
+        let mut closure = states.iter().cloned().collect::<HashSet<State>>();
+        let mut states_to_process = states.to_vec();
 
         while let Some(state) = states_to_process.pop() {
-            let mut column = self.inputs.iter()
-                .map(|input| {
-                    let mut out_state = state.iter()
-                        .map(|sub_state| {
-                            // println!("match ({sub_state}, {input})");
-                            let state = match self.transitions.get(&(*sub_state, *input)) {
-                                Some(state) => state.clone(),
-                                None => vec![]
-                            };
-
-                            state.iter().map(|sub_state| {
-                                state_to_state_combo_map.get(sub_state).cloned().unwrap_or(vec![*sub_state])
-                            })
-                            .flatten()
-                            .collect::<Vec<char>>()
-                        })
-                        .flatten()
-                        .collect::<Vec<char>>();
-
-                    out_state.sort();
-                    out_state.dedup();
-
-                    (*input, out_state)
-                })
-                .filter(|(_, state)| state.len() > 0)
-                .collect::<Vec<(char, Vec<char>)>>();
-
-            // println!("column {column:?} for {state:?}");
-
-            // at this point should be known
-            let associated_state = state_combo_to_state_map.get(&state).unwrap().clone();
-
-            column.iter_mut()
-                .for_each(|(input, state)| {
-                    if state.len() != 1 {
-                        // println!("state: {:?}", state);
-                        // println!("F = {}", self.transitions);
-                        // println!("assocs: {:?}", self.association_map);
-                        // println!("states: {:?}", self.states);
-                        if state.len() > 0 {
-                            if !state_combo_to_state_map.contains_key(&state.to_vec()) {
-                                let new_state = ('A'..='Z').into_iter()
-                                    .filter(|ch| !self.states.contains(ch))
-                                    .next()
-                                    .expect("Not enough capital letters for states");
-    
-                                // println!("!!! {state:?} to {new_state}");
-    
-                                if self.closing_states.iter()
-                                    .any(|closing_state| 
-                                        state.contains(closing_state) && !self.closing_states.contains(&new_state)
-                                    )
-                                {
-                                    self.closing_states.push(new_state);
-                                }
-    
-                                state_combo_to_state_map.insert(state.to_vec(), new_state);
-                                state_to_state_combo_map.insert(new_state, state.to_vec());
-    
-                                self.states.push(new_state);
-    
-                                // println!("push: {state:?}");
-                                states_to_process.push(state.to_vec());
-    
-                                *state = vec![new_state];
-                            } else {
-                                // println!("set to {state:?}");
-                                *state = vec![state_combo_to_state_map.get(&state.to_vec()).unwrap().clone()];
-                            }
-                        }
+            if let Some(out_states) = self.epsilon_transitions.get(&state) {
+                for out_state in out_states {
+                    if closure.insert(out_state.clone()) {
+                        states_to_process.push(out_state.clone());
                     }
+                }
+            }
+        }
+
+        let mut closure = closure.into_iter().collect::<Vec<State>>();
+        closure.sort();
+
+        closure
+    }
+
+    /// Удаление ε-переходов.
+    /// 
+    /// Переход (q, a) ведёт во все состояния, в которые есть переход по a из ε-замыкания q,
+    /// а состояние q становится заключительным, если его ε-замыкание содержит заключительное состояние.
+    pub fn remove_epsilon_transitions(self) -> Self {
+        if !self.has_epsilon_transitions() { return self; }
+
+        let mut transitions = StateTransitionTable::new();
+        let mut closing_states = vec![];
+
+        for state in &self.states {
+            let closure = self.epsilon_closure(std::slice::from_ref(state));
+
+            if closure.iter().any(|state| self.closing_states.contains(state)) {
+                closing_states.push(state.clone());
+            }
 
-                    self.transitions.insert((associated_state, *input), state.clone());
-                });
+            for input in &self.inputs {
+                let mut out_state = closure.iter()
+                    .filter_map(|state| self.transitions.get(&(state.clone(), input.clone())))
+                    .flatten()
+                    .cloned()
+                    .collect::<Vec<State>>();
 
-            // println!("{:?}", self.state_combo_to_state_map);
+                out_state.sort();
+                out_state.dedup();
+
+                if !out_state.is_empty() {
+                    transitions.insert((state.clone(), input.clone()), out_state);
+                }
+            }
         }
 
-        Dfa {
+        Self {
             states: self.states,
             inputs: self.inputs,
-            transitions: self.transitions,
+            transitions,
+            epsilon_transitions: HashMap::new(),
             starting_states: self.starting_states,
-            closing_states: self.closing_states,
+            closing_states
+        }
+    }
+
+    /// Обращение автомата: каждый переход (в том числе ε-переход) меняет направление,
+    /// начальные состояния становятся заключительными, а заключительные - начальными.
+    ///
+    /// Обращённый автомат допускает зеркальные отражения слов исходного языка.
+    pub fn reverse(self) -> Self {
+        let mut reversed = Self {
+            states: self.states,
+            inputs: self.inputs,
+            transitions: StateTransitionTable::new(),
+            epsilon_transitions: HashMap::new(),
+            starting_states: self.closing_states,
+            closing_states: self.starting_states
+        };
+
+        for ((in_state, input), out_states) in self.transitions.iter() {
+            for out_state in out_states {
+                reversed.add_transition(out_state.clone(), input.clone(), in_state.clone());
+            }
+        }
+
+        for (in_state, out_states) in &self.epsilon_transitions {
+            for out_state in out_states {
+                reversed.add_epsilon_transition(out_state.clone(), in_state.clone());
+            }
+        }
+
+        reversed
+    }
+
+    /// Построение ДКА методом подмножеств.
+    /// 
+    /// Подмножества строятся из ε-замыканий: начальное подмножество - замыкание начальных состояний,
+    /// переход по символу ведёт в замыкание множества состояний, достижимых по этому символу.
+    /// 
+    /// Каждое исходное состояние соответствует одноэлементному подмножеству и сохраняет своё имя,
+    /// а для каждого достижимого подмножества из нескольких состояний выделяется новое состояние,
+    /// которое записывается в `state_combo_to_state_map`.
+    pub fn to_deterministic(self) -> Dfa<State, Input> 
+        where State: FromStateId
+    {
+        let closing_states = self.closing_states.iter().collect::<HashSet<&State>>();
+
+        let starting_state = self.epsilon_closure(&self.starting_states);
+
+        let mut subsets = vec![];
+        let mut known_subsets = HashSet::new();
+
+        for subset in self.states.iter()
+            .map(|state| self.epsilon_closure(std::slice::from_ref(state)))
+            .chain([starting_state.clone()])
+            .filter(|subset| !subset.is_empty())
+        {
+            if known_subsets.insert(subset.clone()) {
+                subsets.push(subset);
+            }
+        }
+        let mut subset_transitions = vec![];
+
+        let mut idx = 0;
+
+        while idx < subsets.len() {
+            for input in &self.inputs {
+                let out_state = subsets[idx].iter()
+                    .filter_map(|state| self.transitions.get(&(state.clone(), input.clone())))
+                    .flatten()
+                    .cloned()
+                    .collect::<Vec<State>>();
+
+                if out_state.is_empty() { continue; }
+
+                let out_state = self.epsilon_closure(&out_state);
+
+                if known_subsets.insert(out_state.clone()) {
+                    subsets.push(out_state.clone());
+                }
+
+                subset_transitions.push((idx, input.clone(), out_state));
+            }
+
+            idx += 1;
+        }
+
+        // Выделить новые состояния для подмножеств
+        let mut allocator = StateAllocator::new(self.states.iter().cloned());
+
+        let mut state_combo_to_state_map = HashMap::new();
+        let mut state_to_state_combo_map = HashMap::new();
+
+        let mut states = vec![];
+        let mut new_closing_states = vec![];
+
+        for subset in &subsets {
+            if let [state] = subset.as_slice() {
+                if closing_states.contains(state) {
+                    new_closing_states.push(state.clone());
+                }
+
+                states.push(state.clone());
+
+                continue;
+            }
+
+            let new_state = allocator.allocate();
+
+            if subset.iter().any(|state| closing_states.contains(state)) {
+                new_closing_states.push(new_state.clone());
+            }
+
+            states.push(new_state.clone());
+
+            state_combo_to_state_map.insert(subset.clone(), new_state.clone());
+            state_to_state_combo_map.insert(new_state, subset.clone());
+        }
+
+        let subset_to_state = |subset: &Vec<State>| -> State {
+            match subset.as_slice() {
+                [state] => state.clone(),
+                _ => state_combo_to_state_map.get(subset).cloned().unwrap()
+            }
+        };
+
+        let mut transitions = StateTransitionTable::new();
+
+        for (idx, input, out_state) in subset_transitions {
+            transitions.insert((subset_to_state(&subsets[idx]), input), vec![subset_to_state(&out_state)]);
+        }
+
+        let starting_states = match starting_state.is_empty() {
+            true => vec![],
+            false => vec![subset_to_state(&starting_state)]
+        };
+
+        Dfa {
+            states,
+            inputs: self.inputs,
+            transitions,
+            starting_states,
+            closing_states: new_closing_states,
             state_combo_to_state_map,
             state_to_state_combo_map
         }
@@ -0,0 +1,113 @@
+use std::{collections::HashMap, hash::Hash};
+
+use super::{Dfa, FromStateId, StateAllocator, StateTransitionTable};
+
+impl<State, Input> Dfa<State, Input> 
+    where State: FromStateId + Eq + Hash + Ord + Clone, Input: Eq + Hash + Clone
+{
+    /// Пересечение языков: L(M1) ∩ L(M2).
+    pub fn intersection(&self, other: &Dfa<State, Input>) -> Dfa<State, Input> {
+        self.product(other, |left, right| left && right)
+    }
+
+    /// Объединение языков: L(M1) ∪ L(M2).
+    pub fn union(&self, other: &Dfa<State, Input>) -> Dfa<State, Input> {
+        self.product(other, |left, right| left || right)
+    }
+
+    /// Разность языков: L(M1) \ L(M2).
+    pub fn difference(&self, other: &Dfa<State, Input>) -> Dfa<State, Input> {
+        self.product(other, |left, right| left && !right)
+    }
+
+    /// Симметрическая разность языков: L(M1) △ L(M2).
+    pub fn symmetric_difference(&self, other: &Dfa<State, Input>) -> Dfa<State, Input> {
+        self.product(other, |left, right| left != right)
+    }
+
+    /// Построение произведения автоматов.
+    /// 
+    /// Состояния произведения - пары достижимых состояний (q1, q2), алфавит - объединение алфавитов.
+    /// Отсутствующий переход ведёт в неявное тупиковое состояние, которое обозначается как `None`.
+    /// Пара состояний является заключительной, если `is_closing` возвращает true для признаков
+    /// заключительности её компонент.
+    fn product(&self, other: &Dfa<State, Input>, is_closing: impl Fn(bool, bool) -> bool) -> Dfa<State, Input> {
+        let mut inputs = self.inputs.clone();
+
+        other.inputs.iter()
+            .for_each(|input| {
+                if !inputs.contains(input) {
+                    inputs.push(input.clone());
+                }
+            });
+
+        let next_state = |dfa: &Dfa<State, Input>, state: &Option<State>, input: &Input| -> Option<State> {
+            state.as_ref()
+                .and_then(|state| dfa.transitions.get(&(state.clone(), input.clone())))
+                .and_then(|out_state| out_state.first().cloned())
+        };
+
+        let starting_pair = (self.starting_states.first().cloned(), other.starting_states.first().cloned());
+
+        let mut allocator = StateAllocator::<State>::default();
+        let mut pair_to_state = HashMap::new();
+        let mut pairs = vec![];
+
+        let mut states = vec![];
+        let mut closing_states = vec![];
+        let mut transitions = StateTransitionTable::new();
+
+        let mut get_state = |pair: (Option<State>, Option<State>), pairs: &mut Vec<(Option<State>, Option<State>)>| -> State {
+            if let Some(state) = pair_to_state.get(&pair) {
+                return State::clone(state);
+            }
+
+            let state = allocator.allocate();
+
+            let is_left_closing = pair.0.as_ref().is_some_and(|state| self.closing_states.contains(state));
+            let is_right_closing = pair.1.as_ref().is_some_and(|state| other.closing_states.contains(state));
+
+            if is_closing(is_left_closing, is_right_closing) {
+                closing_states.push(state.clone());
+            }
+
+            states.push(state.clone());
+            pair_to_state.insert(pair.clone(), state.clone());
+            pairs.push(pair);
+
+            state
+        };
+
+        let starting_state = get_state(starting_pair, &mut pairs);
+
+        let mut idx = 0;
+
+        while idx < pairs.len() {
+            let (left, right) = pairs[idx].clone();
+            let in_state = get_state((left.clone(), right.clone()), &mut pairs);
+
+            for input in &inputs {
+                let out_pair = (next_state(self, &left, input), next_state(other, &right, input));
+
+                // Пара тупиковых состояний не допускает ни одной цепочки
+                if out_pair == (None, None) { continue; }
+
+                let out_state = get_state(out_pair, &mut pairs);
+
+                transitions.insert((in_state.clone(), input.clone()), vec![out_state]);
+            }
+
+            idx += 1;
+        }
+
+        Dfa {
+            states,
+            inputs,
+            transitions,
+            starting_states: vec![starting_state],
+            closing_states,
+            state_combo_to_state_map: HashMap::new(),
+            state_to_state_combo_map: HashMap::new()
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use std::{fmt::Display, hash::Hash};
+
+use super::{Dfa, Nfa};
+
+/// Шаг работы автомата: прочитанный символ, сработавшие переходы
+/// и множество текущих состояний после чтения символа.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunStep<State, Input> {
+    pub input: Input,
+    /// Переходы (из состояния, в состояние) по прочитанному символу.
+    pub fired_transitions: Vec<(State, State)>,
+    pub states: Vec<State>,
+}
+
+/// Трасса работы автомата на входной цепочке.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run<State, Input> {
+    /// Множество текущих состояний до чтения первого символа.
+    pub initial_states: Vec<State>,
+    pub steps: Vec<RunStep<State, Input>>,
+    /// Позиция символа, после чтения которого не осталось ни одного текущего состояния.
+    pub died_at: Option<usize>,
+    pub accepted: bool,
+}
+
+impl<State: Display, Input: Display> Display for Run<State, Input> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let fmt_states = |states: &[State]| {
+            let states = states.iter()
+                .map(|state| state.to_string())
+                .collect::<Vec<String>>()
+                .join(", ");
+
+            format!("{{{}}}", states)
+        };
+
+        writeln!(f, "{}", fmt_states(&self.initial_states))?;
+
+        for step in &self.steps {
+            let fired_transitions = step.fired_transitions.iter()
+                .map(|(in_state, out_state)| format!("{} -> {}", in_state, out_state))
+                .collect::<Vec<String>>()
+                .join(", ");
+
+            writeln!(f, "--{}--> {} [{}]", step.input, fmt_states(&step.states), fired_transitions)?;
+        }
+
+        if let Some(position) = self.died_at {
+            writeln!(f, "Автомат остановился на символе {}", position)?;
+        }
+
+        match self.accepted {
+            true => write!(f, "Цепочка допускается"),
+            false => write!(f, "Цепочка не допускается")
+        }
+    }
+}
+
+impl<State, Input> Run<State, Input> 
+    where State: Eq + Hash + Ord + Clone, Input: Eq + Hash + Clone
+{
+    /// Моделирует работу автомата, отслеживая множество текущих состояний.
+    /// 
+    /// `step` возвращает переходы из состояния по символу, `closure` - замыкание множества состояний.
+    pub(crate) fn simulate(
+        initial_states: Vec<State>,
+        word: &[Input],
+        closing_states: &[State],
+        step: impl Fn(&State, &Input) -> Vec<State>,
+        closure: impl Fn(&[State]) -> Vec<State>
+    ) -> Self {
+        let mut states = closure(&initial_states);
+
+        let mut run = Self {
+            initial_states: states.clone(),
+            steps: vec![],
+            died_at: None,
+            accepted: false
+        };
+
+        for (position, input) in word.iter().enumerate() {
+            let fired_transitions = states.iter()
+                .flat_map(|in_state| {
+                    step(in_state, input).into_iter()
+                        .map(|out_state| (in_state.clone(), out_state))
+                })
+                .collect::<Vec<(State, State)>>();
+
+            let mut out_states = fired_transitions.iter()
+                .map(|(_, out_state)| out_state.clone())
+                .collect::<Vec<State>>();
+
+            out_states.sort();
+            out_states.dedup();
+
+            states = closure(&out_states);
+
+            run.steps.push(RunStep {
+                input: input.clone(),
+                fired_transitions,
+                states: states.clone()
+            });
+
+            if states.is_empty() {
+                run.died_at = Some(position);
+
+                return run;
+            }
+        }
+
+        run.accepted = states.iter().any(|state| closing_states.contains(state));
+
+        run
+    }
+}
+
+impl<State, Input> Nfa<State, Input> 
+    where State: Eq + Hash + Ord + Clone, Input: Eq + Hash + Clone
+{
+    /// Трасса работы автомата на цепочке `word` с учётом ε-переходов.
+    pub fn run(&self, word: &[Input]) -> Run<State, Input> {
+        Run::simulate(
+            self.starting_states.clone(),
+            word,
+            &self.closing_states,
+            |state, input| self.transitions.get(&(state.clone(), input.clone())).cloned().unwrap_or_default(),
+            |states| self.epsilon_closure(states)
+        )
+    }
+
+    pub fn accepts(&self, word: &[Input]) -> bool {
+        self.run(word).accepted
+    }
+}
+
+impl<State, Input> Dfa<State, Input> 
+    where State: Eq + Hash + Ord + Clone, Input: Eq + Hash + Clone
+{
+    /// Трасса работы автомата на цепочке `word`.
+    pub fn run(&self, word: &[Input]) -> Run<State, Input> {
+        Run::simulate(
+            self.starting_states.clone(),
+            word,
+            &self.closing_states,
+            |state, input| self.transitions.get(&(state.clone(), input.clone())).cloned().unwrap_or_default(),
+            |states| {
+                let mut states = states.to_vec();
+                states.sort();
+                states.dedup();
+
+                states
+            }
+        )
+    }
+
+    pub fn accepts(&self, word: &[Input]) -> bool {
+        self.run(word).accepted
+    }
+}
@@ -0,0 +1,112 @@
+use std::{collections::{HashMap, HashSet}, hash::Hash};
+
+/// Состояние автомата, которое может быть построено по числовому идентификатору.
+///
+/// Разным идентификаторам должны соответствовать разные состояния.
+pub trait FromStateId: Sized {
+    fn from_state_id(id: usize) -> Self;
+}
+
+impl FromStateId for char {
+    /// Первые 26 идентификаторов отображаются в заглавные латинские буквы,
+    /// остальные - в символы Unicode, начиная с U+0100 (суррогатные пары пропускаются).
+    fn from_state_id(id: usize) -> Self {
+        const LETTERS: usize = 26;
+        const SURROGATES: std::ops::RangeInclusive<u32> = 0xD800..=0xDFFF;
+
+        if id < LETTERS {
+            return (b'A' + id as u8) as char;
+        }
+
+        let mut code = u32::try_from(id - LETTERS)
+            .ok()
+            .and_then(|offset| offset.checked_add(0x100))
+            .expect("Not enough characters for states");
+
+        if code >= *SURROGATES.start() {
+            code += SURROGATES.end() - SURROGATES.start() + 1;
+        }
+
+        char::from_u32(code).expect("Not enough characters for states")
+    }
+}
+
+macro_rules! impl_from_state_id_for_numbers {
+    ($($ty:ty),*) => {
+        $(
+            impl FromStateId for $ty {
+                fn from_state_id(id: usize) -> Self {
+                    <$ty>::try_from(id).expect("Not enough numbers for states")
+                }
+            }
+        )*
+    };
+}
+
+impl_from_state_id_for_numbers!(u8, u16, u32, u64, usize);
+
+impl FromStateId for String {
+    fn from_state_id(id: usize) -> Self {
+        format!("q{}", id)
+    }
+}
+
+/// Распределитель новых состояний автомата.
+///
+/// Выдаёт состояния по возрастанию числовых идентификаторов, пропуская уже занятые,
+/// и при необходимости запоминает отображаемые имена выданных состояний.
+#[derive(Debug, Clone)]
+pub struct StateAllocator<State: Eq + Hash> {
+    next_id: usize,
+    taken: HashSet<State>,
+    names: HashMap<State, String>,
+}
+
+impl<State: Eq + Hash> Default for StateAllocator<State> {
+    fn default() -> Self {
+        Self {
+            next_id: 0,
+            taken: HashSet::new(),
+            names: HashMap::new()
+        }
+    }
+}
+
+impl<State: FromStateId + Eq + Hash + Clone> StateAllocator<State> {
+    /// Создаёт распределитель, который не будет выдавать состояния из `taken`.
+    pub fn new(taken: impl IntoIterator<Item = State>) -> Self {
+        Self {
+            taken: taken.into_iter().collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Помечает состояние как занятое.
+    pub fn reserve(&mut self, state: State) {
+        self.taken.insert(state);
+    }
+
+    pub fn allocate(&mut self) -> State {
+        loop {
+            let state = State::from_state_id(self.next_id);
+            self.next_id += 1;
+
+            if self.taken.insert(state.clone()) {
+                return state;
+            }
+        }
+    }
+
+    pub fn allocate_named(&mut self, name: impl Into<String>) -> State {
+        let state = self.allocate();
+
+        self.names.insert(state.clone(), name.into());
+
+        state
+    }
+
+    /// Отображаемое имя состояния, если оно было задано при выделении.
+    pub fn name(&self, state: &State) -> Option<&str> {
+        self.names.get(state).map(String::as_str)
+    }
+}
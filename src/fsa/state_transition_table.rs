@@ -1,16 +1,29 @@
-use std::{collections::{hash_map::{Iter, IterMut}, HashMap}, fmt::Display};
+use std::{collections::{hash_map::{Iter, IterMut}, HashMap}, fmt::Display, hash::Hash};
 
-#[derive(Default, Debug, Clone, PartialEq, Eq)]
-pub struct StateTransitionTable {
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StateTransitionTable<State: Eq + Hash, Input: Eq + Hash> {
     /// State -> Column
-    columns: HashMap<(char, char), Vec<char>>,
+    columns: HashMap<(State, Input), Vec<State>>,
 }
 
-impl Display for StateTransitionTable {
+impl<State: Eq + Hash, Input: Eq + Hash> Default for StateTransitionTable<State, Input> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<State, Input> Display for StateTransitionTable<State, Input>
+    where State: Eq + Hash + Display, Input: Eq + Hash + Display
+{
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let transitions = self.columns.iter()
             .map(|((in_state, input), out_state)| {
-                format!("({}, {}) -> {:?}", in_state, input, out_state)
+                let out_state = out_state.iter()
+                    .map(|state| state.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ");
+
+                format!("({}, {}) -> [{}]", in_state, input, out_state)
             })
             .collect::<Vec<String>>();
 
@@ -18,8 +31,8 @@ impl Display for StateTransitionTable {
     }
 }
 
-impl From<Vec<((char, char), Vec<char>)>> for StateTransitionTable {
-    fn from(value: Vec<((char, char), Vec<char>)>) -> Self {
+impl<State: Eq + Hash, Input: Eq + Hash> From<Vec<((State, Input), Vec<State>)>> for StateTransitionTable<State, Input> {
+    fn from(value: Vec<((State, Input), Vec<State>)>) -> Self {
         let mut table = StateTransitionTable::new();
 
         for ((in_state, input), out_state) in value {
@@ -30,42 +43,46 @@ impl From<Vec<((char, char), Vec<char>)>> for StateTransitionTable {
     }
 }
 
-impl StateTransitionTable {
+impl<State: Eq + Hash, Input: Eq + Hash> StateTransitionTable<State, Input> {
     pub fn new() -> Self {
         Self {
             columns: Default::default()
         }
     }
 
-    pub fn insert(&mut self, key: (char, char), value: Vec<char>) -> Option<Vec<char>> {
+    pub fn insert(&mut self, key: (State, Input), value: Vec<State>) -> Option<Vec<State>> {
         self.columns.insert(key, value)
     }
 
-    pub fn get(&self, key: &(char, char)) -> Option<&Vec<char>> {
+    pub fn get(&self, key: &(State, Input)) -> Option<&Vec<State>> {
         self.columns.get(key)
     }
 
-    pub fn get_mut(&mut self, key: &(char, char)) -> Option<&mut Vec<char>> {
+    pub fn get_mut(&mut self, key: &(State, Input)) -> Option<&mut Vec<State>> {
         self.columns.get_mut(key)
     }
 
-    pub fn iter(&self) -> Iter<'_, (char, char), Vec<char>> {
+    pub fn iter(&self) -> Iter<'_, (State, Input), Vec<State>> {
         self.columns.iter()
     }
 
-    pub fn iter_mut(&mut self) -> IterMut<'_, (char, char), Vec<char>> {
+    pub fn iter_mut(&mut self) -> IterMut<'_, (State, Input), Vec<State>> {
         self.columns.iter_mut()
     }
 
-    pub fn remove(&mut self, key: &(char, char)) -> Option<Vec<char>> {
+    pub fn remove(&mut self, key: &(State, Input)) -> Option<Vec<State>> {
         self.columns.remove(key)
     }
 
-    pub fn remove_entry(&mut self, key: &(char, char)) -> Option<((char, char), Vec<char>)> {
+    pub fn remove_entry(&mut self, key: &(State, Input)) -> Option<((State, Input), Vec<State>)> {
         self.columns.remove_entry(key)
     }
 
     pub fn len(&self) -> usize {
         self.columns.len()
     }
-}
\ No newline at end of file
+
+    pub fn is_empty(&self) -> bool {
+        self.columns.is_empty()
+    }
+}
@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+
+use super::{Grammar, Symbol};
+
+impl Grammar {
+    /// Множества FIRST нетерминалов: терминалы, с которых начинаются цепочки, выводимые из нетерминала.
+    /// Если из нетерминала выводится ε, множество содержит ε.
+    pub fn first_sets(&self) -> HashMap<Symbol, Vec<Symbol>> {
+        if !self.is_context_free() { return HashMap::new(); }
+
+        let mut sets = self.non_terminals.iter()
+            .map(|non_terminal| (non_terminal.clone(), vec![]))
+            .collect::<HashMap<Symbol, Vec<Symbol>>>();
+
+        loop {
+            let mut is_changed = false;
+
+            for rule in &self.rules {
+                for variant in &rule.variants {
+                    let first = Grammar::first_of_sequence_with(variant, &sets);
+                    let set = sets.entry(rule.input[0].clone()).or_default();
+
+                    first.into_iter().for_each(|sym| {
+                        if !set.contains(&sym) {
+                            set.push(sym);
+                            is_changed = true;
+                        }
+                    });
+                }
+            }
+
+            if !is_changed {
+                break;
+            }
+        }
+
+        sets
+    }
+
+    /// Множества FOLLOW нетерминалов: терминалы, которые могут следовать за нетерминалом
+    /// в сентенциальных формах. Множество начального символа содержит маркер конца цепочки $.
+    pub fn follow_sets(&self) -> HashMap<Symbol, Vec<Symbol>> {
+        self.follow_sets_with(&self.first_sets())
+    }
+
+    /// Множество FIRST цепочки символов X₁...Xₙ. Содержит ε, если ε выводится из всех Xᵢ.
+    pub fn first_of_sequence(&self, sequence: &[Symbol]) -> Vec<Symbol> {
+        Grammar::first_of_sequence_with(sequence, &self.first_sets())
+    }
+
+    /// Множество FIRST цепочки по уже вычисленным множествам FIRST нетерминалов.
+    pub(super) fn first_of_sequence_with(sequence: &[Symbol], first_sets: &HashMap<Symbol, Vec<Symbol>>) -> Vec<Symbol> {
+        let mut first = vec![];
+
+        for sym in sequence.iter().filter(|sym| !sym.is_empty()) {
+            if !sym.is_non_terminal() {
+                if !first.contains(sym) {
+                    first.push(sym.clone());
+                }
+
+                return first;
+            }
+
+            let set = first_sets.get(sym).map(Vec::as_slice).unwrap_or_default();
+
+            set.iter()
+                .filter(|sym| !sym.is_empty())
+                .for_each(|sym| {
+                    if !first.contains(sym) {
+                        first.push(sym.clone());
+                    }
+                });
+
+            if !set.contains(&Self::EMPTY_SEQUENCE) {
+                return first;
+            }
+        }
+
+        first.push(Self::EMPTY_SEQUENCE);
+
+        first
+    }
+
+    /// Множества FOLLOW по уже вычисленным множествам FIRST нетерминалов.
+    pub(super) fn follow_sets_with(&self, first_sets: &HashMap<Symbol, Vec<Symbol>>) -> HashMap<Symbol, Vec<Symbol>> {
+        if !self.is_context_free() { return HashMap::new(); }
+
+        let mut sets = self.non_terminals.iter()
+            .map(|non_terminal| (non_terminal.clone(), vec![]))
+            .collect::<HashMap<Symbol, Vec<Symbol>>>();
+
+        sets.entry(self.starting_non_terminal.clone())
+            .or_default()
+            .push(Self::END_MARKER);
+
+        loop {
+            let mut is_changed = false;
+
+            for rule in &self.rules {
+                for variant in &rule.variants {
+                    for (idx, sym) in variant.iter().enumerate().filter(|(_, sym)| sym.is_non_terminal()) {
+                        let mut follow = Grammar::first_of_sequence_with(&variant[idx + 1..], first_sets);
+
+                        // Если остаток цепочки выводит ε, за символом следует то же, что и за левой частью правила
+                        if let Some(pos) = follow.iter().position(Symbol::is_empty) {
+                            follow.remove(pos);
+                            follow.extend(sets.get(&rule.input[0]).cloned().unwrap_or_default());
+                        }
+
+                        let set = sets.entry(sym.clone()).or_default();
+
+                        follow.into_iter().for_each(|sym| {
+                            if !set.contains(&sym) {
+                                set.push(sym);
+                                is_changed = true;
+                            }
+                        });
+                    }
+                }
+            }
+
+            if !is_changed {
+                break;
+            }
+        }
+
+        sets
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::grammar::{Grammar, Symbol};
+
+    #[test]
+    fn test_first_and_follow_sets() {
+        let grammar = Grammar::parse("
+            E -> T E'
+            E' -> + T E' | ε
+            T -> F T'
+            T' -> * F T' | ε
+            F -> \"(\" E \")\" | id
+        ").expect("Failed to parse grammar");
+
+        println!("{}", grammar);
+
+        let sorted = |set: &Vec<Symbol>| {
+            let mut names = set.iter().map(|sym| sym.to_string()).collect::<Vec<String>>();
+            names.sort();
+            names
+        };
+
+        let first_sets = grammar.first_sets();
+
+        first_sets.iter().for_each(|(non_terminal, set)| println!("FIRST({}) = {:?}", non_terminal, sorted(set)));
+
+        assert_eq!(sorted(&first_sets[&Symbol::non_terminal("E")]), vec!["(", "id"]);
+        assert_eq!(sorted(&first_sets[&Symbol::non_terminal("T")]), vec!["(", "id"]);
+        assert_eq!(sorted(&first_sets[&Symbol::non_terminal("F")]), vec!["(", "id"]);
+        assert_eq!(sorted(&first_sets[&Symbol::non_terminal("E'")]), vec!["+", "ε"]);
+        assert_eq!(sorted(&first_sets[&Symbol::non_terminal("T'")]), vec!["*", "ε"]);
+
+        let follow_sets = grammar.follow_sets();
+
+        follow_sets.iter().for_each(|(non_terminal, set)| println!("FOLLOW({}) = {:?}", non_terminal, sorted(set)));
+
+        assert_eq!(sorted(&follow_sets[&Symbol::non_terminal("E")]), vec!["$", ")"]);
+        assert_eq!(sorted(&follow_sets[&Symbol::non_terminal("E'")]), vec!["$", ")"]);
+        assert_eq!(sorted(&follow_sets[&Symbol::non_terminal("T")]), vec!["$", ")", "+"]);
+        assert_eq!(sorted(&follow_sets[&Symbol::non_terminal("T'")]), vec!["$", ")", "+"]);
+        assert_eq!(sorted(&follow_sets[&Symbol::non_terminal("F")]), vec!["$", ")", "*", "+"]);
+
+        let sequence = [Symbol::non_terminal("T'"), Symbol::non_terminal("E'")];
+
+        assert_eq!(sorted(&grammar.first_of_sequence(&sequence)), vec!["*", "+", "ε"]);
+        assert_eq!(sorted(&grammar.first_of_sequence(&[Symbol::non_terminal("T'"), Symbol::terminal(")")])), vec![")", "*"]);
+        assert_eq!(grammar.first_of_sequence(&[]), vec![Symbol::Empty]);
+    }
+}
@@ -0,0 +1,371 @@
+use super::{Grammar, Rule, Symbol};
+
+impl Grammar {
+    /// Устранение левой рекурсии, непосредственной (A -> Aα) и косвенной (A ⇒⁺ Aα).
+    ///
+    /// Нетерминалы нумеруются A₁, ..., Aₙ в порядке правил. Для каждого i варианты Aᵢ -> Aⱼγ, j < i,
+    /// для которых из Aⱼ выводится цепочка, начинающаяся с Aᵢ, заменяются подстановкой правил Aⱼ,
+    /// после чего непосредственная левая рекурсия Aᵢ -> Aᵢα | β заменяется правилами Aᵢ -> βAᵢ'
+    /// и Aᵢ' -> αAᵢ' | ε.
+    ///
+    /// Если левая рекурсия скрыта за нетерминалами, из которых выводится ε (A -> BAα, B ⇒* ε),
+    /// сначала удаляются ε-правила.
+    pub fn eliminate_left_recursion(&mut self) {
+        if !self.is_context_free() { return; }
+
+        let mut grammar = self.clone();
+
+        grammar.remove_left_recursion_in_order();
+
+        if grammar.is_left_recursive() {
+            grammar = self.clone();
+
+            grammar.remove_empty_rules();
+            grammar.remove_left_recursion_in_order();
+        }
+
+        grammar.grammar_type = Grammar::get_type(&grammar.rules);
+
+        *self = grammar;
+    }
+
+    /// Проверка, выводится ли из какого-либо нетерминала A цепочка Aα.
+    pub fn is_left_recursive(&self) -> bool {
+        let nullable_non_terminals = self.nullable_non_terminals();
+
+        self.rules.iter()
+            .map(|rule| &rule.input[0])
+            .any(|non_terminal| self.left_corners(non_terminal, &nullable_non_terminals).contains(non_terminal))
+    }
+
+    /// Левая факторизация: варианты правила A -> αβ₁ | ... | αβₙ с общим префиксом α
+    /// заменяются вариантом A -> αA' и правилом A' -> β₁ | ... | βₙ, где пустой βᵢ заменяется на ε.
+    pub fn left_factor(&mut self) {
+        if !self.is_context_free() { return; }
+
+        let mut idx = 0;
+
+        while idx < self.rules.len() {
+            let input = self.rules[idx].input[0].clone();
+
+            let mut variants: Vec<Vec<Symbol>> = vec![];
+            let mut new_rules = vec![];
+
+            for variant in &self.rules[idx].variants {
+                if variants.iter().any(|other| other.first() == variant.first()) { continue; }
+
+                let group = self.rules[idx].variants.iter()
+                    .filter(|other| other.first() == variant.first())
+                    .collect::<Vec<&Vec<Symbol>>>();
+
+                if group.len() == 1 || variant.first().is_none_or(Symbol::is_empty) {
+                    variants.push(variant.clone());
+                    continue;
+                }
+
+                let prefix_len = (1..variant.len())
+                    .take_while(|len| group.iter().all(|other| other.get(*len) == variant.get(*len)))
+                    .count() + 1;
+
+                let primed_non_terminal = self.primed_non_terminal(&input);
+
+                self.non_terminals.push(primed_non_terminal.clone());
+
+                let mut factored = variant[..prefix_len].to_vec();
+                factored.push(primed_non_terminal.clone());
+                variants.push(factored);
+
+                let mut tails = vec![];
+
+                group.iter()
+                    .map(|other| match other.len() == prefix_len {
+                        true => vec![Self::EMPTY_SEQUENCE],
+                        false => other[prefix_len..].to_vec()
+                    })
+                    .for_each(|tail| {
+                        if !tails.contains(&tail) {
+                            tails.push(tail);
+                        }
+                    });
+
+                new_rules.push(Rule { input: vec![primed_non_terminal], variants: tails });
+            }
+
+            self.rules[idx].variants = variants;
+            self.rules.extend(new_rules);
+
+            idx += 1;
+        }
+
+        self.grammar_type = Grammar::get_type(&self.rules);
+    }
+
+    fn remove_left_recursion_in_order(&mut self) {
+        let order = self.rules.iter()
+            .map(|rule| rule.input[0].clone())
+            .collect::<Vec<Symbol>>();
+
+        for i in 0..order.len() {
+            for j in 0..i {
+                if self.left_corners(&order[j], &[]).contains(&order[i]) {
+                    self.substitute_leading_non_terminal(&order[i], &order[j]);
+                }
+            }
+
+            self.remove_direct_left_recursion(&order[i], true);
+        }
+    }
+
+    /// Нетерминалы, с которых может начинаться цепочка, выводимая из нетерминала.
+    /// Нетерминалы из nullable_non_terminals в начале вариантов пропускаются.
+    fn left_corners(&self, non_terminal: &Symbol, nullable_non_terminals: &[Symbol]) -> Vec<Symbol> {
+        let mut list = vec![];
+        let mut queue = vec![non_terminal.clone()];
+
+        while let Some(current) = queue.pop() {
+            self.rules.iter()
+                .filter(|rule| rule.input[0] == current)
+                .flat_map(|rule| &rule.variants)
+                .for_each(|variant| {
+                    for sym in variant.iter().filter(|sym| !sym.is_empty()) {
+                        if sym.is_non_terminal() && !list.contains(sym) {
+                            list.push(sym.clone());
+                            queue.push(sym.clone());
+                        }
+
+                        if !nullable_non_terminals.contains(sym) {
+                            break;
+                        }
+                    }
+                });
+        }
+
+        list
+    }
+
+    /// Замена вариантов A -> Bγ правила A вариантами A -> βγ для всех правил B -> β.
+    pub(super) fn substitute_leading_non_terminal(&mut self, input: &Symbol, non_terminal: &Symbol) {
+        let substitutions = self.rules.iter()
+            .filter(|rule| rule.input[0] == *non_terminal)
+            .flat_map(|rule| rule.variants.clone())
+            .collect::<Vec<Vec<Symbol>>>();
+
+        let Some(rule) = self.rules.iter_mut().find(|rule| rule.input[0] == *input) else { return; };
+
+        let mut variants = vec![];
+
+        rule.variants.iter()
+            .flat_map(|variant| match variant.first() {
+                Some(first) if first == non_terminal => substitutions.iter()
+                    .map(|substitution| {
+                        let variant = substitution.iter()
+                            .chain(&variant[1..])
+                            .filter(|sym| !sym.is_empty())
+                            .cloned()
+                            .collect::<Vec<Symbol>>();
+
+                        match variant.is_empty() {
+                            true => vec![Self::EMPTY_SEQUENCE],
+                            false => variant
+                        }
+                    })
+                    .collect::<Vec<Vec<Symbol>>>(),
+                _ => vec![variant.clone()]
+            })
+            .for_each(|variant| {
+                if !variants.contains(&variant) {
+                    variants.push(variant);
+                }
+            });
+
+        rule.variants = variants;
+    }
+
+    /// Устранение непосредственной левой рекурсии в правиле A -> Aα₁ | ... | Aαₘ | β₁ | ... | βₙ.
+    ///
+    /// Если with_empty, правило заменяется правилами A -> β₁A' | ... | βₙA' и A' -> α₁A' | ... | αₘA' | ε,
+    /// иначе - правилами без ε: A -> β₁ | ... | βₙ | β₁A' | ... | βₙA' и A' -> α₁ | ... | αₘ | α₁A' | ... | αₘA'.
+    /// Варианты A -> A отбрасываются.
+    pub(super) fn remove_direct_left_recursion(&mut self, non_terminal: &Symbol, with_empty: bool) {
+        let Some(idx) = self.rules.iter().position(|rule| rule.input[0] == *non_terminal) else { return; };
+
+        let (recursive, other): (Vec<Vec<Symbol>>, Vec<Vec<Symbol>>) = self.rules[idx].variants.iter()
+            .cloned()
+            .partition(|variant| variant.first() == Some(non_terminal));
+
+        if recursive.is_empty() { return; }
+
+        let tails = recursive.into_iter()
+            .map(|variant| variant[1..].to_vec())
+            .filter(|tail| !tail.is_empty())
+            .collect::<Vec<Vec<Symbol>>>();
+
+        if tails.is_empty() {
+            self.rules[idx].variants = other;
+            return;
+        }
+
+        let primed_non_terminal = self.primed_non_terminal(non_terminal);
+
+        let with_primed = |variant: &Vec<Symbol>| variant.iter()
+            .filter(|sym| !sym.is_empty())
+            .chain(std::iter::once(&primed_non_terminal))
+            .cloned()
+            .collect::<Vec<Symbol>>();
+
+        let (variants, primed_variants) = match with_empty {
+            true => (
+                other.iter().map(with_primed).collect::<Vec<Vec<Symbol>>>(),
+                tails.iter().map(with_primed).chain(std::iter::once(vec![Self::EMPTY_SEQUENCE])).collect::<Vec<Vec<Symbol>>>()
+            ),
+            false => (
+                other.iter().cloned().chain(other.iter().map(with_primed)).collect(),
+                tails.iter().cloned().chain(tails.iter().map(with_primed)).collect()
+            )
+        };
+
+        self.rules[idx].variants = variants;
+        self.non_terminals.push(primed_non_terminal.clone());
+        self.rules.push(Rule { input: vec![primed_non_terminal], variants: primed_variants });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{generate, grammar::Symbol};
+
+    #[test]
+    fn test_eliminate_left_recursion() {
+        let mut grammar = generate!{
+            {'+', '*', '(', ')', 'a'},
+            {'E', 'T', 'F'},
+            {
+                "E" -> "E+T" | "T",
+                "T" -> "T*F" | "F",
+                "F" -> "(E)" | "a"
+            },
+            'E'
+        }.expect("Failed to generate grammar");
+
+        assert!(grammar.is_left_recursive(), "Grammar should be left-recursive");
+
+        grammar.eliminate_left_recursion();
+
+        println!("{}", grammar);
+        println!("{}", grammar.grammar_type);
+
+        assert!(!grammar.is_left_recursive(), "Grammar should not be left-recursive: {}", grammar);
+
+        let rules = grammar.rules.iter().map(|rule| rule.to_string()).collect::<Vec<String>>();
+
+        assert_eq!(rules, vec![
+            "E -> T E'",
+            "T -> F T'",
+            "F -> (E) | a",
+            "E' -> + T E' | ε",
+            "T' -> * F T' | ε",
+        ]);
+        assert_eq!(grammar.non_terminals, vec!["E", "T", "F", "E'", "T'"]);
+
+        // Косвенная левая рекурсия: S -> Aa -> Sda
+        let mut grammar = generate!{
+            {'a', 'b', 'c', 'd'},
+            {'S', 'A'},
+            {
+                "S" -> "Aa" | "b",
+                "A" -> "Ac" | "Sd" | "ε"
+            },
+            'S'
+        }.expect("Failed to generate grammar");
+
+        assert!(grammar.is_left_recursive(), "Grammar should be left-recursive");
+
+        grammar.eliminate_left_recursion();
+
+        println!("{}", grammar);
+
+        assert!(!grammar.is_left_recursive(), "Grammar should not be left-recursive: {}", grammar);
+
+        let rules = grammar.rules.iter().map(|rule| rule.to_string()).collect::<Vec<String>>();
+
+        assert_eq!(rules, vec![
+            "S -> Aa | b",
+            "A -> b d A' | A'",
+            "A' -> c A' | a d A' | ε",
+        ]);
+
+        // Левая рекурсия, скрытая за B ⇒ ε
+        let mut grammar = generate!{
+            {'a', 'b'},
+            {'S', 'B'},
+            {
+                "S" -> "BSa" | "b",
+                "B" -> "b" | "ε"
+            },
+            'S'
+        }.expect("Failed to generate grammar");
+
+        assert!(grammar.is_left_recursive(), "Grammar should be left-recursive");
+
+        grammar.eliminate_left_recursion();
+
+        println!("{}", grammar);
+
+        assert!(!grammar.is_left_recursive(), "Grammar should not be left-recursive: {}", grammar);
+    }
+
+    #[test]
+    fn test_left_factor() {
+        let mut grammar = generate!{
+            {'i', 't', 'e', 'a', 'b'},
+            {'S', 'E'},
+            {
+                "S" -> "iEtS" | "iEtSeS" | "a",
+                "E" -> "b"
+            },
+            'S'
+        }.expect("Failed to generate grammar");
+
+        grammar.left_factor();
+
+        println!("{}", grammar);
+        println!("{}", grammar.grammar_type);
+
+        let rules = grammar.rules.iter().map(|rule| rule.to_string()).collect::<Vec<String>>();
+
+        assert_eq!(rules, vec![
+            "S -> i E t S S' | a",
+            "E -> b",
+            "S' -> ε | e S",
+        ]);
+
+        let mut grammar = generate!{
+            {'a', 'b', 'c'},
+            {'S'},
+            {
+                "S" -> "abc" | "abS" | "aS" | "c"
+            },
+            'S'
+        }.expect("Failed to generate grammar");
+
+        grammar.left_factor();
+
+        println!("{}", grammar);
+
+        let rules = grammar.rules.iter().map(|rule| rule.to_string()).collect::<Vec<String>>();
+
+        assert_eq!(rules, vec![
+            "S -> a S' | c",
+            "S' -> b S'' | S",
+            "S'' -> c | S",
+        ]);
+
+        // Пустые цепочки вариантов, заданные напрямую, не факторизуются
+        let mut grammar = generate!{ {'a'}, {'S'}, { "S" -> "a" }, 'S' }.expect("Failed to generate grammar");
+        grammar.rules[0].variants = vec![vec![], vec![], vec![Symbol::terminal("a")]];
+
+        grammar.left_factor();
+
+        assert_eq!(grammar.rules[0].variants, vec![vec![], vec![Symbol::terminal("a")]]);
+    }
+}
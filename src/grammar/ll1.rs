@@ -0,0 +1,424 @@
+use std::{collections::HashMap, fmt::Display};
+
+use super::{Grammar, GrammarError, Rule, Symbol};
+
+/// Конфликт таблицы LL(1): в ячейку M[A, a] попадают два варианта правила A.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Ll1Conflict {
+    /// Символ a входит в FIRST обоих вариантов (или из обоих вариантов выводится ε).
+    FirstFirst { non_terminal: Symbol, terminal: Symbol, rules: (Rule, Rule) },
+    /// Символ a входит в FIRST одного варианта и в FOLLOW(A), а из другого варианта выводится ε.
+    FirstFollow { non_terminal: Symbol, terminal: Symbol, rules: (Rule, Rule) },
+}
+
+impl Display for Ll1Conflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (kind, non_terminal, terminal, (first, second)) = match self {
+            Ll1Conflict::FirstFirst { non_terminal, terminal, rules } => ("FIRST/FIRST", non_terminal, terminal, rules),
+            Ll1Conflict::FirstFollow { non_terminal, terminal, rules } => ("FIRST/FOLLOW", non_terminal, terminal, rules)
+        };
+
+        write!(f, "{} конфликт в M[{}, {}]: {} и {}", kind, non_terminal, terminal, first, second)
+    }
+}
+
+/// Таблица предиктивного анализатора LL(1).
+#[derive(Debug, Clone)]
+pub struct Ll1Table {
+    pub non_terminals: Vec<Symbol>,
+    /// Терминалы грамматики и маркер конца цепочки $.
+    pub terminals: Vec<Symbol>,
+    pub starting_non_terminal: Symbol,
+    /// (Нетерминал A, входной символ a) -> вариант α правила A -> α.
+    /// При конфликте в ячейке остаётся первый вариант.
+    pub table: HashMap<(Symbol, Symbol), Vec<Symbol>>,
+    pub conflicts: Vec<Ll1Conflict>,
+}
+
+impl Display for Ll1Table {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let cells = self.non_terminals.iter()
+            .flat_map(|non_terminal| self.terminals.iter().map(move |terminal| (non_terminal, terminal)))
+            .filter_map(|(non_terminal, terminal)| {
+                self.table.get(&(non_terminal.clone(), terminal.clone()))
+                    .map(|variant| {
+                        let rule = Rule { input: vec![non_terminal.clone()], variants: vec![variant.clone()] };
+
+                        format!("M[{}, {}] = {}", non_terminal, terminal, rule)
+                    })
+            })
+            .collect::<Vec<String>>();
+
+        write!(f, "{{\n\t{}\n}}", cells.join("\n\t"))
+    }
+}
+
+/// Дерево разбора: символ грамматики и поддеревья символов правой части применённого правила.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseTree {
+    pub symbol: Symbol,
+    pub children: Vec<ParseTree>,
+}
+
+impl ParseTree {
+    /// Крона дерева - разобранная цепочка терминалов.
+    pub fn leaves(&self) -> Vec<Symbol> {
+        match self.children.is_empty() {
+            true if self.symbol.is_terminal() => vec![self.symbol.clone()],
+            true => vec![],
+            false => self.children.iter().flat_map(ParseTree::leaves).collect()
+        }
+    }
+
+    fn fmt_with_indent(&self, f: &mut std::fmt::Formatter<'_>, depth: usize) -> std::fmt::Result {
+        writeln!(f, "{}{}", "  ".repeat(depth), self.symbol)?;
+
+        self.children.iter().try_for_each(|child| child.fmt_with_indent(f, depth + 1))
+    }
+}
+
+impl Display for ParseTree {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_with_indent(f, 0)
+    }
+}
+
+/// Синтаксическая ошибка предиктивного анализатора.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyntaxError {
+    /// Позиция символа во входной цепочке.
+    pub position: usize,
+    /// Встреченный символ, $ - конец цепочки.
+    pub found: Symbol,
+    /// Символы, которые ожидались на этой позиции.
+    pub expected: Vec<Symbol>,
+}
+
+impl Display for SyntaxError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let expected = self.expected.iter()
+            .map(|sym| sym.to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        write!(f, "Синтаксическая ошибка на позиции {}: встречен {}, ожидался один из {{{}}}", self.position, self.found, expected)
+    }
+}
+
+/// Ошибка предиктивного разбора по таблице LL(1).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Ll1ParseError {
+    // Означает, что таблица содержит конфликты, т.е. грамматика не является LL(1).
+    NotLl1,
+    // Означает, что входная цепочка не выводится в грамматике.
+    Syntax(SyntaxError),
+}
+
+impl Display for Ll1ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Ll1ParseError::NotLl1 => write!(f, "Грамматика не является LL(1)"),
+            Ll1ParseError::Syntax(error) => write!(f, "{}", error)
+        }
+    }
+}
+
+impl Grammar {
+    /// Построение таблицы LL(1).
+    ///
+    /// Вариант α правила A -> α записывается в ячейки M[A, a] для всех a ∈ FIRST(α), а если ε ∈ FIRST(α),
+    /// то и для всех a ∈ FOLLOW(A). Каждая пара вариантов, попадающих в одну ячейку, сохраняется как конфликт.
+    pub fn ll1_table(&self) -> Result<Ll1Table, GrammarError> {
+        if !self.is_context_free() { return Err(GrammarError::NotContextFree); }
+
+        let first_sets = self.first_sets();
+        let follow_sets = self.follow_sets_with(&first_sets);
+
+        let mut table = Ll1Table {
+            non_terminals: self.non_terminals.clone(),
+            terminals: self.terminals.iter().cloned().chain(std::iter::once(Self::END_MARKER)).collect(),
+            starting_non_terminal: self.starting_non_terminal.clone(),
+            table: HashMap::new(),
+            conflicts: vec![],
+        };
+
+        // Все варианты, попадающие в ячейку
+        let mut cells: HashMap<(Symbol, Symbol), Vec<Vec<Symbol>>> = HashMap::new();
+
+        for rule in &self.rules {
+            let non_terminal = &rule.input[0];
+
+            for variant in &rule.variants {
+                let first = Grammar::first_of_sequence_with(variant, &first_sets);
+
+                let by_first = first.iter()
+                    .filter(|sym| !sym.is_empty())
+                    .map(|sym| (sym.clone(), false));
+
+                let by_follow = follow_sets.get(non_terminal)
+                    .filter(|_| first.contains(&Self::EMPTY_SEQUENCE))
+                    .into_iter()
+                    .flatten()
+                    .map(|sym| (sym.clone(), true));
+
+                for (terminal, is_follow) in by_first.chain(by_follow).collect::<Vec<(Symbol, bool)>>() {
+                    let entries = cells.entry((non_terminal.clone(), terminal.clone())).or_default();
+
+                    if entries.contains(variant) { continue; }
+
+                    for other in entries.iter().cloned() {
+                        let is_other_follow = !Grammar::first_of_sequence_with(&other, &first_sets).contains(&terminal);
+
+                        let rules = (
+                            Rule { input: vec![non_terminal.clone()], variants: vec![other] },
+                            Rule { input: vec![non_terminal.clone()], variants: vec![variant.clone()] }
+                        );
+
+                        let conflict = match is_follow == is_other_follow {
+                            true => Ll1Conflict::FirstFirst { non_terminal: non_terminal.clone(), terminal: terminal.clone(), rules },
+                            false => Ll1Conflict::FirstFollow { non_terminal: non_terminal.clone(), terminal: terminal.clone(), rules }
+                        };
+
+                        table.conflicts.push(conflict);
+                    }
+
+                    if entries.is_empty() {
+                        table.table.insert((non_terminal.clone(), terminal.clone()), variant.clone());
+                    }
+
+                    entries.push(variant.clone());
+                }
+            }
+        }
+
+        Ok(table)
+    }
+
+    /// Является ли грамматика LL(1), т.е. нет ли конфликтов в её таблице LL(1).
+    pub fn is_ll1(&self) -> bool {
+        self.ll1_table().is_ok_and(|table| table.is_ll1())
+    }
+}
+
+impl Ll1Table {
+    pub fn is_ll1(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+
+    /// Предиктивный разбор цепочки терминалов по таблице.
+    ///
+    /// Анализатор хранит магазин символов, в начале содержащий начальный символ. Терминал на вершине
+    /// магазина сравнивается с текущим входным символом, нетерминал A заменяется правой частью варианта
+    /// из ячейки M[A, a], где a - текущий входной символ. Символы цепочки сопоставляются с терминалами
+    /// грамматики по имени.
+    ///
+    /// Таблица с конфликтами не задаёт однозначного выбора варианта (а для леворекурсивной грамматики
+    /// разбор бы не завершился), поэтому разбор по ней не выполняется.
+    pub fn parse(&self, input: &[Symbol]) -> Result<ParseTree, Ll1ParseError> {
+        if !self.is_ll1() { return Err(Ll1ParseError::NotLl1); }
+
+        self.parse_predictive(input).map_err(Ll1ParseError::Syntax)
+    }
+
+    fn parse_predictive(&self, input: &[Symbol]) -> Result<ParseTree, SyntaxError> {
+        let tokens = input.iter()
+            .enumerate()
+            .map(|(position, sym)| {
+                self.terminals.iter()
+                    .filter(|terminal| !terminal.is_end())
+                    .find(|terminal| terminal.name() == sym.name())
+                    .cloned()
+                    .ok_or_else(|| SyntaxError {
+                        position,
+                        found: sym.clone(),
+                        expected: self.terminals.iter().filter(|terminal| !terminal.is_end()).cloned().collect()
+                    })
+            })
+            .chain(std::iter::once(Ok(Grammar::END_MARKER)))
+            .collect::<Result<Vec<Symbol>, SyntaxError>>()?;
+
+        // Узлы дерева разбора: символ и индексы поддеревьев
+        let mut nodes: Vec<(Symbol, Vec<usize>)> = vec![(self.starting_non_terminal.clone(), vec![])];
+        let mut stack = vec![0];
+        let mut position = 0;
+
+        while let Some(node) = stack.pop() {
+            let symbol = nodes[node].0.clone();
+            let lookahead = &tokens[position];
+
+            if !symbol.is_non_terminal() {
+                if symbol != *lookahead {
+                    return Err(SyntaxError { position, found: lookahead.clone(), expected: vec![symbol] });
+                }
+
+                position += 1;
+                continue;
+            }
+
+            let Some(variant) = self.table.get(&(symbol.clone(), lookahead.clone())) else {
+                let expected = self.terminals.iter()
+                    .filter(|terminal| self.table.contains_key(&(symbol.clone(), (*terminal).clone())))
+                    .cloned()
+                    .collect();
+
+                return Err(SyntaxError { position, found: lookahead.clone(), expected });
+            };
+
+            let children = variant.iter()
+                .map(|sym| {
+                    nodes.push((sym.clone(), vec![]));
+                    nodes.len() - 1
+                })
+                .collect::<Vec<usize>>();
+
+            stack.extend(children.iter().rev().filter(|child| !nodes[**child].0.is_empty()));
+            nodes[node].1 = children;
+        }
+
+        if !tokens[position].is_end() {
+            return Err(SyntaxError { position, found: tokens[position].clone(), expected: vec![Grammar::END_MARKER] });
+        }
+
+        fn build(nodes: &[(Symbol, Vec<usize>)], node: usize) -> ParseTree {
+            ParseTree {
+                symbol: nodes[node].0.clone(),
+                children: nodes[node].1.iter().map(|child| build(nodes, *child)).collect()
+            }
+        }
+
+        Ok(build(&nodes, 0))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::grammar::{Grammar, Ll1Conflict, Ll1ParseError, Symbol, SyntaxError};
+
+    #[test]
+    fn test_ll1_table() {
+        let grammar = Grammar::parse("
+            E -> T E'
+            E' -> + T E' | ε
+            T -> F T'
+            T' -> * F T' | ε
+            F -> \"(\" E \")\" | id
+        ").expect("Failed to parse grammar");
+
+        let table = grammar.ll1_table().expect("Failed to build LL(1) table");
+
+        println!("{}", table);
+
+        assert!(table.is_ll1(), "Grammar should be LL(1), conflicts: {:?}", table.conflicts);
+        assert_eq!(table.table.len(), 13);
+        assert_eq!(
+            table.table[&(Symbol::non_terminal("E'"), Symbol::terminal(")"))],
+            vec![Symbol::Empty]
+        );
+        assert_eq!(
+            table.table[&(Symbol::non_terminal("T'"), Symbol::End)],
+            vec![Symbol::Empty]
+        );
+        assert_eq!(
+            table.table[&(Symbol::non_terminal("F"), Symbol::terminal("id"))],
+            vec![Symbol::terminal("id")]
+        );
+
+        // Левая рекурсия даёт FIRST/FIRST конфликт
+        let grammar = Grammar::parse("E -> E + T | T; T -> id").expect("Failed to parse grammar");
+
+        let table = grammar.ll1_table().expect("Failed to build LL(1) table");
+
+        table.conflicts.iter().for_each(|conflict| println!("{}", conflict));
+
+        assert!(!grammar.is_ll1());
+        assert_eq!(table.conflicts.len(), 1);
+        assert!(matches!(
+            &table.conflicts[0],
+            Ll1Conflict::FirstFirst { non_terminal, terminal, rules }
+                if *non_terminal == "E" && *terminal == "id" && rules.0.to_string() == "E -> E+T" && rules.1.to_string() == "E -> T"
+        ));
+
+        // Висячий else даёт FIRST/FOLLOW конфликт
+        let grammar = Grammar::parse("
+            S -> if E then S S' | a
+            S' -> else S | ε
+            E -> b
+        ").expect("Failed to parse grammar");
+
+        let table = grammar.ll1_table().expect("Failed to build LL(1) table");
+
+        table.conflicts.iter().for_each(|conflict| println!("{}", conflict));
+
+        assert_eq!(table.conflicts.len(), 1);
+        assert!(matches!(
+            &table.conflicts[0],
+            Ll1Conflict::FirstFollow { non_terminal, terminal, rules }
+                if *non_terminal == "S'" && *terminal == "else" && rules.0.to_string() == "S' -> else S" && rules.1.to_string() == "S' -> ε"
+        ));
+        assert_eq!(
+            table.table[&(Symbol::non_terminal("S'"), Symbol::terminal("else"))],
+            vec![Symbol::terminal("else"), Symbol::non_terminal("S")]
+        );
+    }
+
+    #[test]
+    fn test_ll1_parse() {
+        let grammar = Grammar::parse("
+            E -> T E'
+            E' -> + T E' | ε
+            T -> F T'
+            T' -> * F T' | ε
+            F -> \"(\" E \")\" | id
+        ").expect("Failed to parse grammar");
+
+        let table = grammar.ll1_table().expect("Failed to build LL(1) table");
+
+        let input = Symbol::split("id + id * ( id + id )");
+
+        let tree = table.parse(&input).expect("Failed to parse input");
+
+        println!("{}", tree);
+
+        assert_eq!(tree.symbol, "E");
+        assert_eq!(tree.leaves(), input);
+        assert_eq!(tree.children.iter().map(|child| child.symbol.clone()).collect::<Vec<Symbol>>(), vec!["T", "E'"]);
+
+        let Err(Ll1ParseError::Syntax(error)) = table.parse(&Symbol::split("id + * id")) else {
+            panic!("Input should not be parsed");
+        };
+
+        println!("{}", error);
+
+        assert_eq!(error.position, 2);
+        assert_eq!(error.found, Symbol::terminal("*"));
+        assert_eq!(error.expected, vec![Symbol::terminal("("), Symbol::terminal("id")]);
+
+        let Err(Ll1ParseError::Syntax(error)) = table.parse(&Symbol::split("( id + id")) else {
+            panic!("Input should not be parsed");
+        };
+
+        println!("{}", error);
+
+        assert_eq!(error, SyntaxError { position: 4, found: Symbol::End, expected: vec![Symbol::terminal(")")] });
+
+        let Err(Ll1ParseError::Syntax(error)) = table.parse(&Symbol::split("id id")) else {
+            panic!("Input should not be parsed");
+        };
+
+        println!("{}", error);
+
+        assert_eq!(error.position, 1);
+        assert_eq!(error.expected, vec![Symbol::terminal("+"), Symbol::terminal("*"), Symbol::terminal(")"), Symbol::End]);
+
+        // По таблице леворекурсивной грамматики разбор не выполняется
+        let grammar = Grammar::parse("
+            E -> E + T | T
+            T -> id
+        ").expect("Failed to parse grammar");
+
+        let table = grammar.ll1_table().expect("Failed to build LL(1) table");
+
+        assert!(!table.is_ll1());
+        assert_eq!(table.parse(&[Symbol::terminal("id")]), Err(Ll1ParseError::NotLl1));
+    }
+}
@@ -0,0 +1,505 @@
+use std::{collections::HashMap, fmt::Display};
+
+use crate::fsa::{Dfa, StateTransitionTable};
+
+use super::{Grammar, GrammarError, Rule, Symbol};
+
+/// LR(0)-пункт A -> α·β: вариант правила с позицией точки.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Item {
+    pub input: Symbol,
+    /// Правая часть правила без ε: пункт правила A -> ε имеет вид A -> ·.
+    pub variant: Vec<Symbol>,
+    pub position: usize,
+}
+
+impl Item {
+    /// Символ после точки.
+    pub fn next_symbol(&self) -> Option<&Symbol> {
+        self.variant.get(self.position)
+    }
+
+    /// Точка стоит в конце правила: пункт соответствует свёртке.
+    pub fn is_complete(&self) -> bool {
+        self.position == self.variant.len()
+    }
+
+    /// Правило пункта, где пустая правая часть записывается как ε.
+    pub fn rule(&self) -> Rule {
+        let variant = match self.variant.is_empty() {
+            true => vec![Grammar::EMPTY_SEQUENCE],
+            false => self.variant.clone()
+        };
+
+        Rule { input: vec![self.input.clone()], variants: vec![variant] }
+    }
+}
+
+impl Display for Item {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let is_single_char = std::iter::once(&self.input)
+            .chain(&self.variant)
+            .all(|sym| sym.to_string().chars().count() == 1);
+
+        let separator = if is_single_char { "" } else { " " };
+
+        let sequence_to_string = |sequence: &[Symbol]| sequence.iter()
+            .map(|sym| sym.to_string())
+            .collect::<Vec<String>>()
+            .join(separator);
+
+        write!(
+            f, "{} -> {}·{}",
+            self.input,
+            sequence_to_string(&self.variant[..self.position]),
+            sequence_to_string(&self.variant[self.position..])
+        )
+    }
+}
+
+/// Канонический автомат LR(0): множества пунктов пополненной грамматики и переходы goto между ними.
+#[derive(Debug, Clone)]
+pub struct Lr0Automaton {
+    /// Пополненная грамматика с новым начальным символом S' и правилом S' -> S.
+    pub grammar: Grammar,
+    /// Множества пунктов Iₙ, номер множества - номер состояния автомата.
+    pub item_sets: Vec<Vec<Item>>,
+    /// goto(Iₙ, X) = Iₘ.
+    pub transitions: HashMap<(u32, Symbol), u32>,
+}
+
+impl Display for Lr0Automaton {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (state, items) in self.item_sets.iter().enumerate() {
+            let items = items.iter()
+                .map(|item| item.to_string())
+                .collect::<Vec<String>>()
+                .join(", ");
+
+            writeln!(f, "I{} = {{{}}}", state, items)?;
+        }
+
+        let symbols = self.symbols();
+
+        let transitions = (0..self.item_sets.len() as u32)
+            .flat_map(|state| symbols.iter().map(move |sym| (state, sym)))
+            .filter_map(|(state, sym)| {
+                self.transitions.get(&(state, sym.clone()))
+                    .map(|out_state| format!("goto(I{}, {}) = I{}", state, sym, out_state))
+            })
+            .collect::<Vec<String>>();
+
+        write!(f, "{{\n\t{}\n}}", transitions.join("\n\t"))
+    }
+}
+
+impl Lr0Automaton {
+    /// Замыкание множества пунктов: для каждого пункта A -> α·Bβ добавляются пункты B -> ·γ.
+    pub fn closure(&self, items: &[Item]) -> Vec<Item> {
+        let mut closure = items.to_vec();
+        let mut idx = 0;
+
+        while idx < closure.len() {
+            if let Some(non_terminal) = closure[idx].next_symbol().filter(|sym| sym.is_non_terminal()).cloned() {
+                self.grammar.rules.iter()
+                    .filter(|rule| rule.input[0] == non_terminal)
+                    .flat_map(|rule| &rule.variants)
+                    .map(|variant| Item {
+                        input: non_terminal.clone(),
+                        variant: variant.iter().filter(|sym| !sym.is_empty()).cloned().collect(),
+                        position: 0
+                    })
+                    .for_each(|item| {
+                        if !closure.contains(&item) {
+                            closure.push(item);
+                        }
+                    });
+            }
+
+            idx += 1;
+        }
+
+        closure
+    }
+
+    /// goto(I, X): замыкание пунктов A -> αX·β для всех пунктов A -> α·Xβ из I.
+    pub fn goto(&self, items: &[Item], symbol: &Symbol) -> Vec<Item> {
+        let mut kernel = items.iter()
+            .filter(|item| item.next_symbol() == Some(symbol))
+            .map(|item| Item { position: item.position + 1, ..item.clone() })
+            .collect::<Vec<Item>>();
+
+        kernel.sort();
+
+        self.closure(&kernel)
+    }
+
+    /// Представление автомата в виде ДКА над символами грамматики. Заключительные состояния -
+    /// множества, содержащие пункт свёртки A -> α·, т.е. автомат распознаёт основы.
+    pub fn to_dfa(&self) -> Dfa<u32, Symbol> {
+        let states = (0..self.item_sets.len() as u32).collect::<Vec<u32>>();
+
+        let transitions = self.transitions.iter()
+            .map(|((state, sym), out_state)| ((*state, sym.clone()), vec![*out_state]))
+            .collect::<Vec<((u32, Symbol), Vec<u32>)>>();
+
+        let closing_states = states.iter()
+            .filter(|state| self.item_sets[**state as usize].iter().any(Item::is_complete))
+            .cloned()
+            .collect();
+
+        Dfa {
+            states,
+            inputs: self.symbols(),
+            transitions: StateTransitionTable::from(transitions),
+            starting_states: vec![0],
+            closing_states,
+
+            state_combo_to_state_map: HashMap::new(),
+            state_to_state_combo_map: HashMap::new(),
+        }
+    }
+
+    /// Символы пополненной грамматики, по которым есть переходы.
+    fn symbols(&self) -> Vec<Symbol> {
+        self.grammar.terminals.iter()
+            .chain(&self.grammar.non_terminals)
+            .filter(|sym| self.transitions.keys().any(|(_, other)| other == *sym))
+            .cloned()
+            .collect()
+    }
+}
+
+/// Действие анализатора SLR(1) в таблице ACTION.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    /// Перенос входного символа и переход в состояние.
+    Shift(u32),
+    /// Свёртка по правилу.
+    Reduce(Rule),
+    Accept,
+}
+
+impl Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Action::Shift(state) => write!(f, "s{}", state),
+            Action::Reduce(rule) => write!(f, "r({})", rule),
+            Action::Accept => write!(f, "acc")
+        }
+    }
+}
+
+/// Конфликт таблицы ACTION: в ячейку ACTION[n, a] попадают два действия.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LrConflict {
+    /// Перенос или свёртка.
+    ShiftReduce { state: u32, terminal: Symbol, shift: u32, reduce: Rule },
+    /// Свёртка по одному из двух правил.
+    ReduceReduce { state: u32, terminal: Symbol, rules: (Rule, Rule) },
+}
+
+impl Display for LrConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LrConflict::ShiftReduce { state, terminal, shift, reduce } =>
+                write!(f, "Конфликт перенос/свёртка в ACTION[{}, {}]: s{} и r({})", state, terminal, shift, reduce),
+            LrConflict::ReduceReduce { state, terminal, rules: (first, second) } =>
+                write!(f, "Конфликт свёртка/свёртка в ACTION[{}, {}]: r({}) и r({})", state, terminal, first, second)
+        }
+    }
+}
+
+/// Таблицы ACTION и GOTO анализатора SLR(1).
+#[derive(Debug, Clone)]
+pub struct SlrTable {
+    pub automaton: Lr0Automaton,
+    /// (Состояние, терминал или $) -> действие. При конфликте в ячейке остаётся перенос или первая свёртка.
+    pub action: HashMap<(u32, Symbol), Action>,
+    /// (Состояние, нетерминал) -> состояние.
+    pub goto: HashMap<(u32, Symbol), u32>,
+    pub conflicts: Vec<LrConflict>,
+}
+
+impl Display for SlrTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let grammar = &self.automaton.grammar;
+
+        let cells = (0..self.automaton.item_sets.len() as u32)
+            .flat_map(|state| {
+                let actions = grammar.terminals.iter()
+                    .chain(std::iter::once(&Grammar::END_MARKER))
+                    .filter_map(move |sym| {
+                        self.action.get(&(state, sym.clone()))
+                            .map(|action| format!("ACTION[{}, {}] = {}", state, sym, action))
+                    });
+
+                let gotos = grammar.non_terminals.iter()
+                    .filter_map(move |sym| {
+                        self.goto.get(&(state, sym.clone()))
+                            .map(|out_state| format!("GOTO[{}, {}] = {}", state, sym, out_state))
+                    });
+
+                actions.chain(gotos)
+            })
+            .collect::<Vec<String>>();
+
+        write!(f, "{{\n\t{}\n}}", cells.join("\n\t"))
+    }
+}
+
+impl SlrTable {
+    pub fn is_slr1(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+}
+
+impl Grammar {
+    /// Построение канонической системы множеств LR(0)-пунктов.
+    ///
+    /// Грамматика пополняется новым начальным символом S' и правилом S' -> S. Начальное множество -
+    /// closure({S' -> ·S}), остальные получаются переходами goto по символам, стоящим после точки.
+    pub fn lr0_automaton(&self) -> Result<Lr0Automaton, GrammarError> {
+        if !self.is_context_free() { return Err(GrammarError::NotContextFree); }
+
+        let mut grammar = self.clone();
+
+        let starting_non_terminal = grammar.primed_non_terminal(&grammar.starting_non_terminal);
+
+        grammar.non_terminals.insert(0, starting_non_terminal.clone());
+        grammar.rules.insert(0, Rule {
+            input: vec![starting_non_terminal.clone()],
+            variants: vec![vec![grammar.starting_non_terminal.clone()]]
+        });
+        grammar.starting_non_terminal = starting_non_terminal.clone();
+        grammar.grammar_type = Grammar::get_type(&grammar.rules);
+
+        let mut automaton = Lr0Automaton {
+            grammar,
+            item_sets: vec![],
+            transitions: HashMap::new(),
+        };
+
+        let initial = automaton.closure(&[Item {
+            input: starting_non_terminal,
+            variant: vec![self.starting_non_terminal.clone()],
+            position: 0
+        }]);
+
+        automaton.item_sets.push(initial);
+
+        let mut idx = 0;
+
+        while idx < automaton.item_sets.len() {
+            let items = automaton.item_sets[idx].clone();
+
+            let mut symbols: Vec<Symbol> = vec![];
+
+            items.iter()
+                .filter_map(Item::next_symbol)
+                .for_each(|sym| {
+                    if !symbols.contains(sym) {
+                        symbols.push(sym.clone());
+                    }
+                });
+
+            for sym in symbols {
+                let out_items = automaton.goto(&items, &sym);
+
+                let out_state = match automaton.item_sets.iter().position(|other| *other == out_items) {
+                    Some(out_state) => out_state,
+                    None => {
+                        automaton.item_sets.push(out_items);
+                        automaton.item_sets.len() - 1
+                    }
+                };
+
+                automaton.transitions.insert((idx as u32, sym), out_state as u32);
+            }
+
+            idx += 1;
+        }
+
+        Ok(automaton)
+    }
+
+    /// Построение таблиц ACTION и GOTO анализатора SLR(1).
+    ///
+    /// Для пункта A -> α·aβ из Iₙ и goto(Iₙ, a) = Iₘ записывается перенос ACTION[n, a] = sm,
+    /// для пункта A -> α· - свёртка ACTION[n, a] = r(A -> α) для всех a ∈ FOLLOW(A),
+    /// для пункта S' -> S· - допуск ACTION[n, $] = acc. Переходы по нетерминалам образуют таблицу GOTO.
+    /// Каждая пара действий, попадающих в одну ячейку, сохраняется как конфликт.
+    pub fn slr_table(&self) -> Result<SlrTable, GrammarError> {
+        let automaton = self.lr0_automaton()?;
+
+        let follow_sets = automaton.grammar.follow_sets();
+
+        let mut action: HashMap<(u32, Symbol), Action> = HashMap::new();
+        let mut conflicts = vec![];
+
+        let goto = automaton.transitions.iter()
+            .filter(|((_, sym), _)| sym.is_non_terminal())
+            .map(|(key, out_state)| (key.clone(), *out_state))
+            .collect::<HashMap<(u32, Symbol), u32>>();
+
+        for (state, items) in automaton.item_sets.iter().enumerate() {
+            let state = state as u32;
+
+            let actions = items.iter()
+                .flat_map(|item| match item.next_symbol() {
+                    Some(sym) if !sym.is_non_terminal() => {
+                        let out_state = automaton.transitions[&(state, sym.clone())];
+
+                        vec![(sym.clone(), Action::Shift(out_state))]
+                    },
+                    Some(_) => vec![],
+                    None if item.input == automaton.grammar.starting_non_terminal => vec![(Grammar::END_MARKER, Action::Accept)],
+                    None => follow_sets.get(&item.input)
+                        .into_iter()
+                        .flatten()
+                        .map(|sym| (sym.clone(), Action::Reduce(item.rule())))
+                        .collect()
+                })
+                .collect::<Vec<(Symbol, Action)>>();
+
+            for (terminal, new_action) in actions {
+                let key = (state, terminal.clone());
+
+                let Some(old_action) = action.get(&key).cloned() else {
+                    action.insert(key, new_action);
+                    continue;
+                };
+
+                // Допуск - свёртка по правилу S' -> S
+                let as_rule = |action: &Action| match action {
+                    Action::Reduce(rule) => Some(rule.clone()),
+                    Action::Accept => Some(automaton.grammar.rules[0].clone()),
+                    Action::Shift(_) => None
+                };
+
+                match (&old_action, &new_action) {
+                    _ if old_action == new_action => {},
+                    (Action::Shift(shift), _) | (_, Action::Shift(shift)) => {
+                        let reduce = as_rule(&old_action).or_else(|| as_rule(&new_action)).unwrap();
+
+                        conflicts.push(LrConflict::ShiftReduce { state, terminal, shift: *shift, reduce });
+
+                        if let Action::Shift(_) = new_action {
+                            action.insert(key, new_action);
+                        }
+                    },
+                    _ => {
+                        let rules = (as_rule(&old_action).unwrap(), as_rule(&new_action).unwrap());
+
+                        conflicts.push(LrConflict::ReduceReduce { state, terminal, rules });
+                    }
+                }
+            }
+        }
+
+        Ok(SlrTable { automaton, action, goto, conflicts })
+    }
+
+    /// Является ли грамматика SLR(1), т.е. нет ли конфликтов в её таблице ACTION.
+    pub fn is_slr1(&self) -> bool {
+        self.slr_table().is_ok_and(|table| table.is_slr1())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::grammar::{Action, Grammar, LrConflict, Symbol};
+
+    #[test]
+    fn test_lr0_automaton() {
+        let grammar = Grammar::parse("
+            E -> E + T | T
+            T -> T * F | F
+            F -> \"(\" E \")\" | id
+        ").expect("Failed to parse grammar");
+
+        let automaton = grammar.lr0_automaton().expect("Failed to build LR(0) automaton");
+
+        println!("{}", automaton);
+
+        assert_eq!(automaton.grammar.starting_non_terminal, "E'");
+        assert_eq!(automaton.item_sets.len(), 12);
+        assert_eq!(
+            automaton.item_sets[0].iter().map(|item| item.to_string()).collect::<Vec<String>>(),
+            vec!["E' -> ·E", "E -> ·E+T", "E -> ·T", "T -> ·T*F", "T -> ·F", "F -> ·(E)", "F -> ·id"]
+        );
+
+        let dfa = automaton.to_dfa();
+
+        println!("{}", dfa);
+
+        assert_eq!(dfa.states.len(), 12);
+        assert_eq!(dfa.starting_states, vec![0]);
+        assert_eq!(dfa.transitions.len(), automaton.transitions.len());
+        assert_eq!(dfa.closing_states, vec![1, 2, 3, 5, 9, 10, 11], "Expected states with complete items, got: {:?}", dfa.closing_states);
+
+        // Основа T * F распознаётся из начального состояния
+        let handle = [Symbol::non_terminal("T"), Symbol::terminal("*"), Symbol::non_terminal("F")];
+
+        let state = handle.iter()
+            .try_fold(0, |state, sym| automaton.transitions.get(&(state, sym.clone())).cloned())
+            .expect("Handle should be recognized");
+
+        assert!(dfa.closing_states.contains(&state));
+        assert_eq!(automaton.item_sets[state as usize][0].to_string(), "T -> T*F·");
+    }
+
+    #[test]
+    fn test_slr_table() {
+        let grammar = Grammar::parse("
+            E -> E + T | T
+            T -> T * F | F
+            F -> \"(\" E \")\" | id
+        ").expect("Failed to parse grammar");
+
+        let table = grammar.slr_table().expect("Failed to build SLR(1) table");
+
+        println!("{}", table);
+
+        assert!(table.is_slr1(), "Grammar should be SLR(1), conflicts: {:?}", table.conflicts);
+        assert_eq!(table.action[&(0, Symbol::terminal("id"))], Action::Shift(5));
+        assert_eq!(table.action[&(1, Symbol::End)], Action::Accept);
+        assert_eq!(table.goto[&(0, Symbol::non_terminal("E"))], 1);
+        assert_eq!(table.action.values().filter(|action| matches!(action, Action::Reduce(_))).count(), 22);
+
+        // Присваивание из книги Ахо: SLR(1) даёт конфликт перенос/свёртка
+        let grammar = Grammar::parse("
+            S -> L = R | R
+            L -> * R | id
+            R -> L
+        ").expect("Failed to parse grammar");
+
+        let table = grammar.slr_table().expect("Failed to build SLR(1) table");
+
+        table.conflicts.iter().for_each(|conflict| println!("{}", conflict));
+
+        assert!(!grammar.is_slr1());
+        assert_eq!(table.conflicts.len(), 1);
+        assert!(matches!(
+            &table.conflicts[0],
+            LrConflict::ShiftReduce { terminal, reduce, .. } if *terminal == "=" && reduce.to_string() == "R -> L"
+        ));
+
+        let grammar = Grammar::parse("
+            S -> A | B
+            A -> a
+            B -> a
+        ").expect("Failed to parse grammar");
+
+        let table = grammar.slr_table().expect("Failed to build SLR(1) table");
+
+        table.conflicts.iter().for_each(|conflict| println!("{}", conflict));
+
+        assert_eq!(table.conflicts.len(), 1);
+        assert!(matches!(
+            &table.conflicts[0],
+            LrConflict::ReduceReduce { terminal, rules, .. }
+                if *terminal == Symbol::End && rules.0.to_string() == "A -> a" && rules.1.to_string() == "B -> a"
+        ));
+    }
+}
@@ -1,9 +1,9 @@
 #[macro_export]
 macro_rules! rule {
     ($a:literal -> $($b:literal)|+) => {
-        crate::grammar::Rule { 
-            input: $a.chars().collect(), 
-            variants: vec![$($b.chars().collect()),+] 
+        $crate::grammar::Rule { 
+            input: $crate::grammar::Symbol::split($a), 
+            variants: vec![$($crate::grammar::Symbol::split($b)),+] 
         }
     }
 }
@@ -11,19 +11,29 @@ macro_rules! rule {
 #[macro_export]
 macro_rules! rules {
     ($($a:literal -> $($b:literal)|+),*) => {
-        vec![$(crate::rule! { $a -> $($b)|* }),*]
+        vec![$($crate::rule! { $a -> $($b)|* }),*]
     }
 }
 
 #[macro_export]
 macro_rules! generate {
     ({$($non_term:literal),*}, {$($term:literal),*}, $rules:ident, $start:literal) => {
-        crate::grammar::Grammar::new(vec![$($non_term),*], vec![$($term),*], $start, $rules)
+        $crate::grammar::Grammar::from_written_rules(
+            vec![$($crate::grammar::Symbol::from($non_term)),*], 
+            vec![$($crate::grammar::Symbol::from($term)),*], 
+            $start, 
+            $rules
+        )
     };
     ($non_terms:ident, $terms:ident, $rules:ident, $start:literal) => {
-        crate::grammar::Grammar::new($non_terms, $terms, $start, $rules)
+        $crate::grammar::Grammar::from_written_rules($non_terms, $terms, $start, $rules)
     };
     ({$($non_term:literal),*}, {$($term:literal),*}, {$($a:literal -> $($b:literal)|+),*}, $start:literal) => {
-        crate::grammar::Grammar::new(vec![$($non_term),*], vec![$($term),*], $start, vec![$(crate::rule! { $a -> $($b)|* }),*])
+        $crate::grammar::Grammar::from_written_rules(
+            vec![$($crate::grammar::Symbol::from($non_term)),*], 
+            vec![$($crate::grammar::Symbol::from($term)),*], 
+            $start, 
+            vec![$($crate::rule! { $a -> $($b)|* }),*]
+        )
     };
-}
\ No newline at end of file
+}
@@ -1,8 +1,20 @@
 mod macros;
-
-use std::fmt::Display;
-
-#[derive(Debug, PartialEq, Eq)]
+mod symbol;
+pub use symbol::Symbol;
+mod parser;
+mod normal_form;
+mod left_recursion;
+mod first_follow;
+mod ll1;
+pub use ll1::{Ll1Conflict, Ll1ParseError, Ll1Table, ParseTree, SyntaxError};
+mod lr;
+pub use lr::{Action, Item, Lr0Automaton, LrConflict, SlrTable};
+
+use std::{fmt::Display, str::FromStr};
+
+use crate::fsa::StateAllocator;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum RegularType {
     /// Регулярная грамматика, выровненная влево, имеющая правило вывода вида:
     /// 
@@ -14,7 +26,7 @@ pub enum RegularType {
     Right
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum GrammarType {
     /// Грамматика, не имеющая ограничения на её правила вывода, кроме тех, которые указаны в определении грамматики.
     Type0,
@@ -50,28 +62,56 @@ impl Display for GrammarType {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq)]
 pub enum GrammarError {
     // Означает, что в терминальных и нетерминальных символах имеются пересекающиеся символы.
     OverlappingSymbols,
     // Означает, что в множестве нетерминальных символов нет символа S.
     MissingStartingNonTerminalSymbol,
     // Означает, что правило, определённое для грамматики, не подходит.
-    InvalidRule
+    InvalidRule,
+    // Означает, что преобразование определено только для контекстно-свободных грамматик.
+    NotContextFree,
+    // Означает, что при разборе текста грамматики в строке line и столбце column встретилась неожиданная лексема.
+    UnexpectedToken { line: usize, column: usize, token: String },
+    // Означает, что текст грамматики закончился раньше, чем ожидалось.
+    UnexpectedEnd { line: usize, column: usize },
+    // Означает, что строка в кавычках, начинающаяся в строке line и столбце column, не закрыта.
+    UnterminatedString { line: usize, column: usize },
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Rule {
-    pub input: Vec<char>,
-    pub variants: Vec<Vec<char>>,
+    pub input: Vec<Symbol>,
+    pub variants: Vec<Vec<Symbol>>,
+}
+
+impl Rule {
+    /// Цепочки из односимвольных имён записываются слитно (`E+T`), иначе - через пробел (`Expr + Term`).
+    fn sequence_to_string(sequence: &[Symbol], separator: &str) -> String {
+        sequence.iter()
+            .map(|sym| sym.to_string())
+            .collect::<Vec<String>>()
+            .join(separator)
+    }
+
+    fn separator(&self) -> &'static str {
+        let is_single_char = self.input.iter()
+            .chain(self.variants.iter().flatten())
+            .all(|sym| sym.to_string().chars().count() == 1);
+
+        if is_single_char { "" } else { " " }
+    }
 }
 
 impl Display for Rule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let input = String::from_iter(&self.input);
+        let separator = self.separator();
+
+        let input = Rule::sequence_to_string(&self.input, separator);
 
         let variants = self.variants.iter()
-            .map(|variant| String::from_iter(variant).into())
+            .map(|variant| Rule::sequence_to_string(variant, separator))
             .collect::<Vec<String>>()
             .join(" | ");
 
@@ -79,11 +119,44 @@ impl Display for Rule {
     }
 }
 
+/// Отчёт о приведении грамматики: что было удалено на каждом этапе `Grammar::make_equivalent`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReductionReport {
+    /// Непорождающие нетерминалы, из которых не выводится ни одна терминальная цепочка.
+    pub non_generating: Vec<Symbol>,
+    /// Недостижимые из начального символа нетерминалы и терминалы.
+    pub unreachable: Vec<Symbol>,
+    /// Удалённые ε-правила A -> ε.
+    pub empty_rules: Vec<Rule>,
+    /// Удалённые цепные правила A -> B.
+    pub unit_rules: Vec<Rule>,
+}
+
+impl Display for ReductionReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let symbols_to_string = |symbols: &[Symbol]| symbols.iter()
+            .map(|sym| sym.to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        let rules_to_string = |rules: &[Rule]| rules.iter()
+            .map(|rule| format!("{{{rule}}}"))
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        writeln!(f, "Непорождающие символы: {{{}}}", symbols_to_string(&self.non_generating))?;
+        writeln!(f, "Недостижимые символы: {{{}}}", symbols_to_string(&self.unreachable))?;
+        writeln!(f, "ε-правила: {{{}}}", rules_to_string(&self.empty_rules))?;
+        write!(f, "Цепные правила: {{{}}}", rules_to_string(&self.unit_rules))
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct Grammar {
-    pub terminals: Vec<char>,
-    pub non_terminals: Vec<char>,
+    pub terminals: Vec<Symbol>,
+    pub non_terminals: Vec<Symbol>,
     pub rules: Vec<Rule>,
-    pub starting_non_terminal: char,
+    pub starting_non_terminal: Symbol,
     pub grammar_type: GrammarType
 }
 
@@ -92,12 +165,12 @@ impl Display for Grammar {
         let rules = self.rules.iter().map(|rule| format!("{{{rule}}}")).collect::<Vec<String>>().join(", ");
 
         let terminals = self.terminals.iter()
-            .map(|sym| String::from(*sym))
+            .map(|sym| sym.to_string())
             .collect::<Vec<String>>()
             .join(", ");
 
         let non_terminals = self.non_terminals.iter()
-            .map(|sym| String::from(*sym))
+            .map(|sym| sym.to_string())
             .collect::<Vec<String>>()
             .join(", ");
 
@@ -105,17 +178,44 @@ impl Display for Grammar {
     }
 }
 
+impl FromStr for Grammar {
+    type Err = GrammarError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grammar::parse(s)
+    }
+}
+
 impl Grammar {
-    const EMPTY_SEQUENCE: char = 'ε';
+    const EMPTY_SEQUENCE: Symbol = Symbol::Empty;
+    const END_MARKER: Symbol = Symbol::End;
 
+    /// Символы правил, которые не входят в Vᴛ, но допускаются в правых частях правил.
+    const OPERATIONS: [&'static str; 4] = ["+", "-", "*", "/"];
+
+    /// Создание грамматики.
+    /// 
+    /// Символы могут быть заданы как `Symbol`, строками или отдельными знаками. Вид символов
+    /// определяется множествами `terminals` и `non_terminals`: символы правил с теми же именами
+    /// становятся терминалами и нетерминалами соответственно. Цепочки символов правил не изменяются.
     pub fn new(
-        terminals: Vec<char>, 
-        non_terminals: Vec<char>, 
-        starting_non_terminal: char,
+        terminals: impl IntoIterator<Item = impl Into<Symbol>>, 
+        non_terminals: impl IntoIterator<Item = impl Into<Symbol>>, 
+        starting_non_terminal: impl Into<Symbol>,
         rules: Vec<Rule>
     ) -> Result<Self, GrammarError> {
+        let terminals = terminals.into_iter()
+            .map(|sym| Symbol::terminal(sym.into().name()))
+            .collect::<Vec<Symbol>>();
+
+        let non_terminals = non_terminals.into_iter()
+            .map(|sym| Symbol::non_terminal(sym.into().name()))
+            .collect::<Vec<Symbol>>();
+
+        let starting_non_terminal = Symbol::non_terminal(starting_non_terminal.into().name());
+
         if terminals.iter()
-            .any(|sym| non_terminals.contains(sym)) 
+            .any(|sym| non_terminals.iter().any(|non_terminal| non_terminal.name() == sym.name())) 
         {
             return Err(GrammarError::OverlappingSymbols);
         }
@@ -124,32 +224,42 @@ impl Grammar {
             return Err(GrammarError::MissingStartingNonTerminalSymbol);
         }
 
-        if !rules.iter()
-            .all(|rule| {
-                let valid_input = rule.input.iter()
-                    .all(|sym| 
-                        terminals.contains(sym) || non_terminals.contains(sym)
-                    );
-                
-                let valid_variants = rule.variants.iter()
-                    .all(|variant| 
-                        variant.iter()
-                            .all(|sym| {
-                                let is_terminal = terminals.contains(sym);
-                                let is_non_terminal = non_terminals.contains(sym);
-                                let is_empty = sym == &Self::EMPTY_SEQUENCE;
-                                let is_operation = ['+', '-', '*', '/'].contains(sym);
+        let classify = |sym: &Symbol| -> Option<Symbol> {
+            if sym.is_empty() {
+                return Some(Self::EMPTY_SEQUENCE);
+            }
 
-                                is_terminal || is_non_terminal || is_empty || is_operation
-                            })
-                        );
+            let non_terminal = Symbol::non_terminal(sym.name());
+            let terminal = Symbol::terminal(sym.name());
 
-                valid_input && valid_variants
-        }) {
-            return Err(GrammarError::InvalidRule);
-        }
+            if non_terminals.contains(&non_terminal) {
+                Some(non_terminal)
+            } else if terminals.contains(&terminal) || Self::OPERATIONS.contains(&sym.name()) {
+                Some(terminal)
+            } else {
+                None
+            }
+        };
+
+        let classify_sequence = |sequence: &[Symbol]| -> Option<Vec<Symbol>> {
+            sequence.iter().map(classify).collect()
+        };
 
-        let grammar_type = Grammar::get_type(&terminals, &non_terminals, &rules);
+        let rules = rules.into_iter()
+            .map(|rule| {
+                let input = classify_sequence(&rule.input)
+                    .filter(|input| input.iter().all(|sym| !sym.is_empty()))?;
+
+                let variants = rule.variants.iter()
+                    .map(|variant| classify_sequence(variant))
+                    .collect::<Option<Vec<Vec<Symbol>>>>()?;
+
+                Some(Rule { input, variants })
+            })
+            .collect::<Option<Vec<Rule>>>()
+            .ok_or(GrammarError::InvalidRule)?;
+
+        let grammar_type = Grammar::get_type(&rules);
 
         Ok(Self {
             terminals,
@@ -160,6 +270,57 @@ impl Grammar {
         })
     }
 
+    /// Создание грамматики по правилам, записанным строками (см. `rule!` и `generate!`).
+    /// 
+    /// Строка без пробелов разбивается `Symbol::split` на отдельные знаки, поэтому цепочка знаков,
+    /// записанная слитно (`"Term"`), снова становится одним символом, если такое имя есть в грамматике.
+    pub fn from_written_rules(
+        terminals: impl IntoIterator<Item = impl Into<Symbol>>, 
+        non_terminals: impl IntoIterator<Item = impl Into<Symbol>>, 
+        starting_non_terminal: impl Into<Symbol>,
+        rules: Vec<Rule>
+    ) -> Result<Self, GrammarError> {
+        let terminals = terminals.into_iter().map(Into::into).collect::<Vec<Symbol>>();
+        let non_terminals = non_terminals.into_iter().map(Into::into).collect::<Vec<Symbol>>();
+
+        let join = |sequence: Vec<Symbol>| -> Vec<Symbol> {
+            let is_written_together = sequence.len() > 1 && 
+                sequence.iter().all(|sym| !sym.is_empty() && sym.name().chars().count() == 1);
+
+            if !is_written_together { return sequence; }
+
+            let name = sequence.iter().map(Symbol::name).collect::<String>();
+
+            if terminals.iter().chain(&non_terminals).any(|sym| sym.name() == name) {
+                vec![Symbol::from(name)]
+            } else {
+                sequence
+            }
+        };
+
+        let rules = rules.into_iter()
+            .map(|rule| Rule { 
+                input: join(rule.input), 
+                variants: rule.variants.into_iter().map(join).collect() 
+            })
+            .collect();
+
+        Self::new(terminals, non_terminals, starting_non_terminal, rules)
+    }
+
+    /// Разбор грамматики, записанной в нотации BNF/EBNF.
+    /// 
+    /// Каждое правило имеет вид `A -> α | β` или `<A> ::= α | β` и может заканчиваться `;`.
+    /// Символы разделяются пробелами: имя, для которого есть правило, или имя в угловых скобках - нетерминал,
+    /// остальные имена и строки в кавычках - терминалы, `ε` или пустая альтернатива - пустая цепочка.
+    /// Комментарии начинаются с `#` или `//`. Конструкции EBNF `(α | β)`, `[α]` и `{α}` раскрываются
+    /// через новые нетерминалы A', A'', ..., где A - левая часть правила.
+    /// 
+    /// Начальный символ - левая часть первого правила.
+    pub fn parse(text: &str) -> Result<Self, GrammarError> {
+        parser::Parser::new(text)?.parse()
+    }
+
     pub fn is_grammar_language_exists(&self) -> bool {
         if self.grammar_type != GrammarType::ContextFree { return false; }
 
@@ -168,8 +329,10 @@ impl Grammar {
         list.contains(&self.starting_non_terminal)
     }
 
+    /// Удаление непорождающих нетерминалов, из которых не выводится ни одна терминальная цепочка,
+    /// вместе с вариантами правил, в которые они входят.
     pub fn remove_non_ending_non_terminals(&mut self) {
-        if self.grammar_type != GrammarType::ContextFree { return; }
+        if !self.is_context_free() { return; }
 
         let new_non_terminals = self.get_non_terminals_with_terminal_strings();
 
@@ -180,26 +343,28 @@ impl Grammar {
                 let variants = rule.variants.iter()
                     .filter(|variant| 
                         variant.iter()
-                            .all(|ch| 
-                                self.terminals.contains(ch) || 
-                                *ch == Self::EMPTY_SEQUENCE
+                            .all(|sym| 
+                                self.terminals.contains(sym) || 
+                                new_non_terminals.contains(sym) ||
+                                *sym == Self::EMPTY_SEQUENCE
                             )
                         )
                     .cloned()
-                    .collect::<Vec<Vec<char>>>();
+                    .collect::<Vec<Vec<Symbol>>>();
 
                 new_rules.push(
-                    Rule { input: vec![rule.input[0]], variants }
+                    Rule { input: vec![rule.input[0].clone()], variants }
                 );
             }
         });
 
-        self.non_terminals = new_non_terminals;
+        self.non_terminals.retain(|sym| new_non_terminals.contains(sym));
         self.rules = new_rules;     
     } 
 
+    /// Удаление символов, недостижимых из начального символа, вместе с их правилами.
     pub fn remove_unreachable_symbols(&mut self) {
-        let mut non_terminals = vec![self.starting_non_terminal];
+        let mut non_terminals = vec![self.starting_non_terminal.clone()];
         let mut terminals = vec![];
 
         loop {
@@ -209,13 +374,13 @@ impl Grammar {
             self.rules.iter().for_each(|rule| {
                 if new_non_terminals.contains(&rule.input[0]) {
                     rule.variants.iter().for_each(|variant| {
-                        variant.iter().for_each(|ch| {
-                            if self.terminals.contains(ch) && !new_terminals.contains(ch) {
-                                new_terminals.push(*ch);
+                        variant.iter().for_each(|sym| {
+                            if self.terminals.contains(sym) && !new_terminals.contains(sym) {
+                                new_terminals.push(sym.clone());
                             }
 
-                            if self.non_terminals.contains(ch) && !new_non_terminals.contains(ch) {
-                                new_non_terminals.push(*ch);
+                            if self.non_terminals.contains(sym) && !new_non_terminals.contains(sym) {
+                                new_non_terminals.push(sym.clone());
                             }
                         });
                     });
@@ -237,40 +402,299 @@ impl Grammar {
                 let variants = rule.variants.iter()
                     .filter(|variant| 
                         variant.iter()
-                            .all(|ch| 
-                                self.terminals.contains(ch) || 
-                                *ch == Self::EMPTY_SEQUENCE
+                            .all(|sym| 
+                                terminals.contains(sym) || 
+                                non_terminals.contains(sym) ||
+                                *sym == Self::EMPTY_SEQUENCE
                             )
                         )
                     .cloned()
-                    .collect::<Vec<Vec<char>>>();
+                    .collect::<Vec<Vec<Symbol>>>();
 
                 rules.push(
-                    Rule { input: vec![rule.input[0]], variants }
+                    Rule { input: vec![rule.input[0].clone()], variants }
                 );
             }
         });
 
-        self.terminals = terminals;
-        self.non_terminals = non_terminals;
+        self.terminals.retain(|sym| terminals.contains(sym));
+        self.non_terminals.retain(|sym| non_terminals.contains(sym));
         self.rules = rules;
     }
 
-    fn remove_empty_rules(&mut self) {
+    /// Удаление ε-правил.
+    /// 
+    /// Каждый вариант правила заменяется всеми вариантами, полученными вычёркиванием
+    /// некоторых вхождений нетерминалов, из которых выводится ε, после чего правила A -> ε удаляются.
+    /// Нетерминалы, из которых выводится только ε, удаляются вместе с вариантами, в которые они входят.
+    /// 
+    /// Если ε выводится из начального символа S, добавляется новый начальный нетерминал S₀ с правилом
+    /// S₀ -> S | ε, не встречающийся в правых частях правил. В качестве S₀ берётся первая свободная
+    /// заглавная латинская буква (см. `new_non_terminal`).
+    pub fn remove_empty_rules(&mut self) {
+        if !self.is_context_free() { return; }
+
+        let nullable_non_terminals = self.nullable_non_terminals();
+
+        let mut rules = self.rules.iter()
+            .map(|rule| {
+                let mut variants = vec![];
+
+                rule.variants.iter()
+                    .flat_map(|variant| {
+                        variant.iter()
+                            .filter(|sym| !sym.is_empty())
+                            .fold(vec![vec![]], |prefixes: Vec<Vec<Symbol>>, sym| {
+                                prefixes.into_iter()
+                                    .flat_map(|prefix| {
+                                        let mut with_symbol = prefix.clone();
+                                        with_symbol.push(sym.clone());
+
+                                        match nullable_non_terminals.contains(sym) {
+                                            true => vec![with_symbol, prefix],
+                                            false => vec![with_symbol]
+                                        }
+                                    })
+                                    .collect()
+                            })
+                    })
+                    .filter(|variant| !variant.is_empty())
+                    .for_each(|variant| {
+                        if !variants.contains(&variant) {
+                            variants.push(variant);
+                        }
+                    });
+
+                Rule { input: rule.input.clone(), variants }
+            })
+            .collect::<Vec<Rule>>();
+
+        // Нетерминалы без правил выводят только ε
+        loop {
+            let empty_non_terminals = rules.iter()
+                .filter(|rule| rule.variants.is_empty())
+                .map(|rule| rule.input[0].clone())
+                .chain(
+                    self.non_terminals.iter()
+                        .filter(|non_terminal| !rules.iter().any(|rule| rule.input[0] == **non_terminal))
+                        .cloned()
+                )
+                .collect::<Vec<Symbol>>();
+
+            let rules_count = rules.len();
+
+            rules.retain(|rule| !rule.variants.is_empty());
+            rules.iter_mut()
+                .for_each(|rule| 
+                    rule.variants.retain(|variant| !variant.iter().any(|sym| empty_non_terminals.contains(sym)))
+                );
+
+            if rules.len() == rules_count && rules.iter().all(|rule| !rule.variants.is_empty()) {
+                self.non_terminals.retain(|non_terminal| 
+                    !empty_non_terminals.contains(non_terminal) || *non_terminal == self.starting_non_terminal
+                );
+
+                break;
+            }
+        }
+
+        self.rules = rules;
+
+        if nullable_non_terminals.contains(&self.starting_non_terminal) {
+            let starting_non_terminal = self.new_non_terminal();
+
+            let mut variants = vec![vec![Self::EMPTY_SEQUENCE]];
+
+            if self.rules.iter().any(|rule| rule.input[0] == self.starting_non_terminal) {
+                variants.insert(0, vec![self.starting_non_terminal.clone()]);
+            } else {
+                self.non_terminals.retain(|non_terminal| *non_terminal != self.starting_non_terminal);
+            }
+
+            self.non_terminals.push(starting_non_terminal.clone());
+            self.rules.push(Rule { input: vec![starting_non_terminal.clone()], variants });
+            self.starting_non_terminal = starting_non_terminal;
+        }
+
+        self.grammar_type = Grammar::get_type(&self.rules);
+    }
+
+    /// Удаление цепных правил вида A -> B.
+    /// 
+    /// Для каждого нетерминала A строится множество нетерминалов, выводимых из A только по цепным правилам
+    /// (включая сам A), и правило A получает все нецепные варианты правил этих нетерминалов.
+    pub fn remove_unit_rules(&mut self) {
+        if !self.is_context_free() { return; }
+
+        let is_unit = |variant: &Vec<Symbol>| variant.len() == 1 && variant[0].is_non_terminal();
+
+        let rules = self.rules.iter()
+            .map(|rule| {
+                // Цепное замыкание нетерминала
+                let mut unit_non_terminals = vec![rule.input[0].clone()];
+                let mut idx = 0;
+
+                while idx < unit_non_terminals.len() {
+                    let non_terminal = unit_non_terminals[idx].clone();
+
+                    self.rules.iter()
+                        .filter(|rule| rule.input[0] == non_terminal)
+                        .flat_map(|rule| &rule.variants)
+                        .filter(|variant| is_unit(variant))
+                        .for_each(|variant| {
+                            if !unit_non_terminals.contains(&variant[0]) {
+                                unit_non_terminals.push(variant[0].clone());
+                            }
+                        });
+
+                    idx += 1;
+                }
+
+                let mut variants = vec![];
+
+                unit_non_terminals.iter()
+                    .flat_map(|non_terminal| self.rules.iter().filter(move |rule| rule.input[0] == *non_terminal))
+                    .flat_map(|rule| &rule.variants)
+                    .filter(|variant| !is_unit(variant))
+                    .for_each(|variant| {
+                        if !variants.contains(variant) {
+                            variants.push(variant.clone());
+                        }
+                    });
+
+                Rule { input: rule.input.clone(), variants }
+            })
+            .collect::<Vec<Rule>>();
+
+        self.rules = rules;
+        self.grammar_type = Grammar::get_type(&self.rules);
+    }
+
+    /// Нетерминалы, из которых выводится пустая цепочка ε.
+    pub fn nullable_non_terminals(&self) -> Vec<Symbol> {
+        let mut list = vec![];
+
+        loop {
+            let mut new_list = list.clone();
+
+            for rule in &self.rules {
+                if rule.variants.iter()
+                    .any(|variant| 
+                        variant.iter().all(|sym| *sym == Self::EMPTY_SEQUENCE || new_list.contains(sym))
+                    ) &&
+                    !new_list.contains(&rule.input[0])
+                {
+                    new_list.push(rule.input[0].clone());
+                }
+            }
+
+            if new_list == list {
+                break;
+            } else {
+                list = new_list;
+            }
+        }
+
+        list
+    }
+
+    /// Правила всех грамматик типов 2 и 3 имеют в левой части один нетерминал.
+    fn is_context_free(&self) -> bool {
+        matches!(self.grammar_type, GrammarType::ContextFree | GrammarType::Regular(_))
+    }
+
+    /// Новый нетерминал - первая заглавная латинская буква (а после них - первый знак Unicode),
+    /// не занятая символами грамматики.
+    fn new_non_terminal(&self) -> Symbol {
+        let taken = self.terminals.iter()
+            .chain(&self.non_terminals)
+            .filter_map(|sym| {
+                let mut chars = sym.name().chars();
+
+                match (chars.next(), chars.next()) {
+                    (Some(ch), None) => Some(ch),
+                    _ => None
+                }
+            });
+
+        Symbol::non_terminal(StateAllocator::<char>::new(taken).allocate())
+    }
+
+    /// Новый нетерминал A', A'', ... для нетерминала A, не занятый символами грамматики.
+    fn primed_non_terminal(&self, non_terminal: &Symbol) -> Symbol {
+        let mut name = format!("{}'", non_terminal.name());
+
+        while self.terminals.iter().chain(&self.non_terminals).any(|sym| *sym == name.as_str()) {
+            name.push('\'');
+        }
 
+        Symbol::non_terminal(name)
     }
 
-    pub fn make_equivalent(&mut self) {
-        if self.grammar_type != GrammarType::ContextFree { return; }
+    /// Приведение контекстно-свободной грамматики к эквивалентной.
+    /// 
+    /// Этапы выполняются по порядку: удаление непорождающих нетерминалов, удаление недостижимых символов,
+    /// удаление ε-правил и удаление цепных правил. Удаление цепных правил может сделать часть нетерминалов
+    /// недостижимыми, поэтому в конце недостижимые символы удаляются ещё раз.
+    /// 
+    /// Возвращает отчёт о том, что было удалено на каждом этапе.
+    pub fn make_equivalent(&mut self) -> ReductionReport {
+        let mut report = ReductionReport::default();
+
+        if !self.is_context_free() { return report; }
+
+        let non_terminals = self.non_terminals.clone();
+
+        self.remove_non_ending_non_terminals();
+
+        report.non_generating = non_terminals.into_iter()
+            .filter(|non_terminal| !self.non_terminals.contains(non_terminal))
+            .collect();
+
+        report.unreachable = self.removed_unreachable_symbols();
+
+        report.empty_rules = self.rules.iter()
+            .filter(|rule| rule.variants.iter().any(|variant| variant.iter().all(Symbol::is_empty)))
+            .map(|rule| Rule { input: rule.input.clone(), variants: vec![vec![Self::EMPTY_SEQUENCE]] })
+            .collect();
+
+        self.remove_empty_rules();
+
+        report.unit_rules = self.rules.iter()
+            .map(|rule| Rule {
+                input: rule.input.clone(),
+                variants: rule.variants.iter()
+                    .filter(|variant| variant.len() == 1 && variant[0].is_non_terminal())
+                    .cloned()
+                    .collect()
+            })
+            .filter(|rule| !rule.variants.is_empty())
+            .collect();
+
+        self.remove_unit_rules();
 
+        report.unreachable.extend(self.removed_unreachable_symbols());
 
+        self.grammar_type = Grammar::get_type(&self.rules);
+
+        report
+    }
+
+    /// Удаление недостижимых символов, возвращающее удалённые нетерминалы и терминалы.
+    fn removed_unreachable_symbols(&mut self) -> Vec<Symbol> {
+        let symbols = self.non_terminals.iter()
+            .chain(&self.terminals)
+            .cloned()
+            .collect::<Vec<Symbol>>();
+
+        self.remove_unreachable_symbols();
+
+        symbols.into_iter()
+            .filter(|sym| !self.non_terminals.contains(sym) && !self.terminals.contains(sym))
+            .collect()
     }
 
-    fn get_type(
-        terminals: &Vec<char>, 
-        non_terminals: &Vec<char>, 
-        rules: &Vec<Rule>
-    ) -> GrammarType {
+    fn get_type(rules: &[Rule]) -> GrammarType {
         let mut grammar_type = GrammarType::Type0;
 
         // check for type 1
@@ -297,14 +721,21 @@ impl Grammar {
         if rules.iter()
             .all(|rule| {
                 rule.variants.iter().all(|variant| {
-                    let is_left_aligned = non_terminals.iter().any(|sym| variant.starts_with(&[*sym]));
-                    let is_right_aligned = non_terminals.iter().any(|sym| variant.ends_with(&[*sym]));
-                    let is_terminated = variant.len() == 1 && terminals.contains(&variant[0]);
+                    let is_left_aligned = variant.first().is_some_and(Symbol::is_non_terminal);
+                    let is_right_aligned = variant.last().is_some_and(Symbol::is_non_terminal);
+                    let is_terminated = variant.len() == 1 && variant[0].is_terminal();
                     let is_empty = variant.len() == 1 && variant[0] == Self::EMPTY_SEQUENCE;
 
-                    match (is_left_aligned, is_right_aligned) {
-                        (true, false) => regular_type = Some(RegularType::Left),
-                        (false, true) => regular_type = Some(RegularType::Right),
+                    let alignment = match (is_left_aligned, is_right_aligned) {
+                        (true, false) => Some(RegularType::Left),
+                        (false, true) => Some(RegularType::Right),
+                        _ => None
+                    };
+
+                    // Грамматика, смешивающая выравнивание влево и вправо, не является регулярной
+                    match (alignment, &regular_type) {
+                        (Some(alignment), Some(existing)) if alignment != *existing => return false,
+                        (Some(alignment), _) => regular_type = Some(alignment),
                         _ => { 
                             if !is_terminated && !is_empty { 
                                 return false;
@@ -316,13 +747,14 @@ impl Grammar {
                 })
             })
         {
-            grammar_type = GrammarType::Regular(regular_type.unwrap());
+            // Правила вида A -> a и A -> ε подходят под оба вида
+            grammar_type = GrammarType::Regular(regular_type.unwrap_or(RegularType::Right));
         }
 
         grammar_type
     }
 
-    fn get_non_terminals_with_terminal_strings(&self) -> Vec<char> {
+    fn get_non_terminals_with_terminal_strings(&self) -> Vec<Symbol> {
         let mut list = vec![];
 
         loop {
@@ -334,16 +766,16 @@ impl Grammar {
                         rule.input.contains(non_terminal) && 
                         rule.variants.iter()
                             .any(|variant|
-                                variant.iter().all(|ch| 
-                                    self.terminals.contains(ch) || 
-                                    new_list.contains(ch) || 
-                                    *ch == Self::EMPTY_SEQUENCE
+                                variant.iter().all(|sym| 
+                                    self.terminals.contains(sym) || 
+                                    new_list.contains(sym) || 
+                                    *sym == Self::EMPTY_SEQUENCE
                                 )
                             )
                         ) &&
                     !new_list.contains(non_terminal)
                 {
-                    new_list.push(*non_terminal);
+                    new_list.push(non_terminal.clone());
                 }
             }
 
@@ -361,7 +793,7 @@ impl Grammar {
 #[cfg(test)]
 mod test {
     use crate::{
-        generate, grammar::{Grammar, GrammarType, RegularType}, rule
+        generate, grammar::{Grammar, GrammarError, GrammarType, ReductionReport, RegularType, Rule, Symbol}, rule
     };
 
     #[test]
@@ -420,6 +852,173 @@ mod test {
         assert_eq!(grammar.grammar_type, GrammarType::Type0, "Expected type 0 grammar, got: {}", grammar.grammar_type);
     }
 
+    #[test]
+    fn test_named_symbols() {
+        let grammar = generate!{
+            {"id", "+", "*", "(", ")"},
+            {"Expr", "Term", "Term'", "Factor"},
+            {
+                "Expr" -> "Expr + Term" | "Term",
+                "Term" -> "Factor Term'",
+                "Term'" -> "* Factor Term'" | "ε",
+                "Factor" -> "( Expr )" | "id"
+            },
+            "Expr"
+        }.expect("Failed to generate grammar");
+
+        println!("{}", grammar);
+        println!("{}", grammar.grammar_type);
+
+        assert_eq!(grammar.grammar_type, GrammarType::ContextFree, "Expected context-free grammar type, got: {}", grammar.grammar_type);
+        assert_eq!(grammar.starting_non_terminal, Symbol::non_terminal("Expr"));
+        assert_eq!(grammar.rules[0].variants[1], vec![Symbol::non_terminal("Term")]);
+        assert_eq!(
+            grammar.rules[2].variants[0], 
+            vec![Symbol::terminal("*"), Symbol::non_terminal("Factor"), Symbol::non_terminal("Term'")]
+        );
+        assert_eq!(grammar.rules[2].variants[1], vec![Symbol::Empty]);
+
+        assert_eq!(grammar.rules[0].to_string(), "Expr -> Expr + Term | Term");
+        assert_eq!(grammar.rules[3].to_string(), "Factor -> ( Expr ) | id");
+
+        let grammar = generate!{
+            {'a', 'b'},
+            {'S', 'A'},
+            {
+                "S" -> "aA" | "ε",
+                "A" -> "b"
+            },
+            'S'
+        }.expect("Failed to generate grammar");
+
+        assert_eq!(grammar.to_string(), "G = { {a, b}, {S, A}, {{S -> aA | ε}, {A -> b}}, S }");
+
+        let grammar = Grammar::new(
+            [Symbol::terminal("a b"), Symbol::terminal("|")], 
+            ["S"], 
+            "S", 
+            vec![Rule { input: vec![Symbol::from("S")], variants: vec![vec![Symbol::from("a b"), Symbol::from("|")]] }]
+        ).expect("Failed to generate grammar");
+
+        assert_eq!(grammar.rules[0].to_string(), r#"S -> "a b" "|""#);
+
+        // Заданные цепочки символов не объединяются, даже если есть символ с объединённым именем
+        let grammar = Grammar::new(
+            ["a", "b", "ab"], 
+            ["S"], 
+            "S", 
+            vec![Rule { 
+                input: vec![Symbol::from("S")], 
+                variants: vec![vec![Symbol::from("a"), Symbol::from("b")], vec![Symbol::from("ab")]] 
+            }]
+        ).expect("Failed to generate grammar");
+
+        assert_eq!(grammar.rules[0].variants[0], vec![Symbol::terminal("a"), Symbol::terminal("b")]);
+        assert_eq!(grammar.rules[0].variants[1], vec![Symbol::terminal("ab")]);
+
+        // Терминал с заглавной буквы записывается в кавычках, чтобы не читаться как нетерминал
+        assert_eq!(Symbol::terminal("A").to_string(), r#""A""#);
+        assert_eq!(Symbol::non_terminal("A").to_string(), "A");
+
+        let grammar = Grammar::parse(r#"S -> "A" S | "B" | b"#).expect("Failed to parse grammar");
+        let text = grammar.rules.iter().map(Rule::to_string).collect::<Vec<String>>().join("\n");
+
+        assert_eq!(text, r#"S -> "A" S | "B" | b"#);
+
+        let reparsed = Grammar::parse(&text).expect("Printed grammar should be parsable");
+
+        assert_eq!(reparsed.terminals, grammar.terminals);
+        assert_eq!(reparsed.non_terminals, grammar.non_terminals);
+        assert_eq!(reparsed.rules, grammar.rules);
+
+        assert!(matches!(
+            generate!{ {'a'}, {'S'}, { "S" -> "ab" }, 'S' }, 
+            Err(GrammarError::InvalidRule)
+        ));
+    }
+
+    #[test]
+    fn test_parse_grammar() {
+        let grammar = Grammar::parse("
+            # Арифметические выражения
+            Expr -> Expr + Term | Term
+            Term -> Term * Factor 
+                  | Factor
+            Factor -> \"(\" Expr \")\" | id   // скобки - терминалы
+        ").expect("Failed to parse grammar");
+
+        println!("{}", grammar);
+        println!("{}", grammar.grammar_type);
+
+        assert_eq!(grammar.starting_non_terminal, Symbol::non_terminal("Expr"));
+        assert_eq!(grammar.non_terminals, vec!["Expr", "Term", "Factor"]);
+        assert_eq!(grammar.terminals, vec!["+", "*", "(", ")", "id"]);
+        assert_eq!(grammar.grammar_type, GrammarType::ContextFree);
+        assert_eq!(grammar.rules[2].to_string(), "Factor -> ( Expr ) | id");
+
+        let grammar: Grammar = "
+            <S> ::= a <B> | ε ;
+            <B> ::= b <S> | ;
+        ".parse().expect("Failed to parse grammar");
+
+        println!("{}", grammar);
+
+        assert_eq!(grammar.grammar_type, GrammarType::Regular(RegularType::Right));
+        assert_eq!(grammar.rules[1].variants, vec![vec![Symbol::terminal("b"), Symbol::non_terminal("S")], vec![Symbol::Empty]]);
+
+        // Символы, разделённые пробелом, не объединяются с терминалом с тем же слитным именем
+        let grammar = Grammar::parse("S -> a b | \"ab\"").expect("Failed to parse grammar");
+
+        assert_eq!(grammar.terminals, vec!["a", "b", "ab"]);
+        assert_eq!(grammar.rules[0].variants, vec![vec![Symbol::terminal("a"), Symbol::terminal("b")], vec![Symbol::terminal("ab")]]);
+
+        let grammar = Grammar::parse("
+            List -> Item { \",\" Item }
+            Item -> [ \"-\" ] ( num | id )
+        ").expect("Failed to parse grammar");
+
+        println!("{}", grammar);
+
+        assert_eq!(grammar.non_terminals, vec!["List", "Item", "List'", "Item'", "Item''"]);
+        assert_eq!(grammar.rules.len(), 5);
+        assert_eq!(grammar.rules[0].to_string(), "List -> Item List'");
+        assert_eq!(grammar.rules[1].to_string(), r#"List' -> "," Item List' | ε"#);
+        assert_eq!(grammar.rules[2].to_string(), "Item -> Item' Item''");
+        assert_eq!(grammar.rules[3].to_string(), "Item' -> - | ε");
+        assert_eq!(grammar.rules[4].to_string(), "Item'' -> num | id");
+
+        let grammar = Grammar::parse("S -> a S | b\nS -> c").expect("Failed to parse grammar");
+
+        assert_eq!(grammar.rules.len(), 1);
+        assert_eq!(grammar.rules[0].to_string(), "S -> aS | b | c");
+    }
+
+    #[test]
+    fn test_parse_grammar_errors() {
+        assert_eq!(
+            Grammar::parse("S -> a\n  | b )").err(), 
+            Some(GrammarError::UnexpectedToken { line: 2, column: 7, token: String::from(")") })
+        );
+        assert_eq!(
+            Grammar::parse("S -> { a").err(), 
+            Some(GrammarError::UnexpectedEnd { line: 1, column: 9 })
+        );
+        assert_eq!(
+            Grammar::parse("S -> a\nA -> \"b").err(), 
+            Some(GrammarError::UnterminatedString { line: 2, column: 6 })
+        );
+        assert_eq!(
+            Grammar::parse("S a").err(), 
+            Some(GrammarError::UnexpectedToken { line: 1, column: 3, token: String::from("a") })
+        );
+        assert_eq!(
+            Grammar::parse("S ->\n-> a").err(), 
+            Some(GrammarError::UnexpectedToken { line: 2, column: 1, token: String::from("->") })
+        );
+        assert_eq!(Grammar::parse("# пусто").err(), Some(GrammarError::MissingStartingNonTerminalSymbol));
+        assert_eq!(Grammar::parse("S -> \"S\"").err(), Some(GrammarError::OverlappingSymbols));
+    }
+
     #[test]
     fn test_is_grammar_language_exists() {
         let grammar = generate!{
@@ -515,4 +1114,126 @@ mod test {
         assert_eq!(grammar.starting_non_terminal, 'C', "Invalid starting non-terminal, got: {}", grammar.starting_non_terminal);
         assert_eq!(grammar.terminals, vec!['0', '1'], "Invalid terminals, got: {:?}", grammar.terminals);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_remove_empty_rules_variants() {
+        let mut grammar = generate!{
+            {'a', 'b'},
+            {'S', 'A', 'B', 'E'},
+            {
+                "S" -> "aAb" | "E",
+                "A" -> "aAb" | "B",
+                "B" -> "bB" | "ε",
+                "E" -> "ε"
+            },
+            'S'
+        }.expect("Failed to generate grammar");
+
+        let mut nullable_non_terminals = grammar.nullable_non_terminals();
+        nullable_non_terminals.sort();
+
+        assert_eq!(nullable_non_terminals, vec!['A', 'B', 'E', 'S']);
+
+        grammar.remove_empty_rules();
+
+        println!("{}", grammar);
+        println!("{}", grammar.grammar_type);
+
+        assert_eq!(grammar.non_terminals, vec!['S', 'A', 'B', 'C'], "Invalid non-terminals, got: {:?}", grammar.non_terminals);
+        assert_eq!(grammar.starting_non_terminal, 'C');
+        assert_eq!(grammar.rules[0].to_string(), "S -> aAb | ab");
+        assert_eq!(grammar.rules[1].to_string(), "A -> aAb | ab | B");
+        assert_eq!(grammar.rules[2].to_string(), "B -> bB | b");
+        assert_eq!(grammar.rules[3].to_string(), "C -> S | ε");
+        assert!(
+            grammar.rules.iter()
+                .flat_map(|rule| &rule.variants)
+                .all(|variant| variant != &vec![Symbol::Empty] || grammar.rules[3].variants.contains(variant)),
+            "Only the new starting non-terminal may have an empty rule"
+        );
+    }
+
+    #[test]
+    fn test_remove_unit_rules() {
+        let mut grammar = generate!{
+            {'+', '*', '(', ')', 'a'},
+            {'E', 'T', 'F'},
+            {
+                "E" -> "E+T" | "T",
+                "T" -> "T*F" | "F",
+                "F" -> "(E)" | "a"
+            },
+            'E'
+        }.expect("Failed to generate grammar");
+
+        grammar.remove_unit_rules();
+
+        println!("{}", grammar);
+        println!("{}", grammar.grammar_type);
+
+        assert_eq!(grammar.rules[0].to_string(), "E -> E+T | T*F | (E) | a");
+        assert_eq!(grammar.rules[1].to_string(), "T -> T*F | (E) | a");
+        assert_eq!(grammar.rules[2].to_string(), "F -> (E) | a");
+
+        let mut grammar = generate!{
+            {'a', 'b'},
+            {'S', 'A', 'B'},
+            {
+                "S" -> "A" | "b",
+                "A" -> "B" | "aA",
+                "B" -> "A" | "S" | "a"
+            },
+            'S'
+        }.expect("Failed to generate grammar");
+
+        grammar.remove_unit_rules();
+
+        println!("{}", grammar);
+
+        assert_eq!(grammar.rules[0].to_string(), "S -> b | aA | a");
+        assert_eq!(grammar.rules[1].to_string(), "A -> aA | a | b");
+        assert_eq!(grammar.rules[2].to_string(), "B -> a | aA | b");
+        assert_eq!(grammar.grammar_type, GrammarType::Regular(RegularType::Right));
+    }
+
+    #[test]
+    fn test_make_equivalent() {
+        let mut grammar = generate!{
+            {'a', 'b', 'c'},
+            {'S', 'A', 'B', 'C', 'D', 'E'},
+            {
+                "S" -> "AB" | "C" | "D",
+                "A" -> "aA" | "ε",
+                "B" -> "bB" | "b",
+                "C" -> "cC",
+                "D" -> "ab",
+                "E" -> "c"
+            },
+            'S'
+        }.expect("Failed to generate grammar");
+
+        println!("{}", grammar);
+        println!("{}", grammar.grammar_type);
+
+        let report = grammar.make_equivalent();
+
+        println!("{}", report);
+        println!("{}", grammar);
+        println!("{}", grammar.grammar_type);
+
+        assert_eq!(report, ReductionReport {
+            non_generating: vec![Symbol::non_terminal("C")],
+            unreachable: vec![Symbol::non_terminal("E"), Symbol::terminal("c"), Symbol::non_terminal("D")],
+            empty_rules: vec![rule!("A" -> "ε")],
+            unit_rules: vec![rule!("S" -> "B" | "D")],
+        });
+
+        assert_eq!(grammar.non_terminals, vec!['S', 'A', 'B'], "Invalid non-terminals, got: {:?}", grammar.non_terminals);
+        assert_eq!(grammar.terminals, vec!['a', 'b'], "Invalid terminals, got: {:?}", grammar.terminals);
+        assert_eq!(grammar.rules[0].to_string(), "S -> AB | bB | b | ab");
+        assert_eq!(grammar.rules[1].to_string(), "A -> aA | a");
+        assert_eq!(grammar.rules[2].to_string(), "B -> bB | b");
+        assert_eq!(grammar.rules.len(), 3);
+        assert_eq!(grammar.grammar_type, GrammarType::ContextFree);
+    }
+}
@@ -0,0 +1,369 @@
+use super::{Grammar, GrammarError, Rule, Symbol};
+
+impl Grammar {
+    /// Приведение грамматики к нормальной форме Хомского.
+    ///
+    /// Грамматика сначала приводится (`Grammar::make_equivalent`): удаляются непорождающие
+    /// и недостижимые символы, ε-правила и цепные правила. Затем терминалы в цепочках длины 2 и больше
+    /// заменяются новыми нетерминалами X с правилами X -> a, а цепочки длины k > 2 разбиваются:
+    /// A -> B₁B₂...Bₖ заменяется на A -> B₁C₁, C₁ -> B₂C₂, ..., Cₖ₋₂ -> Bₖ₋₁Bₖ.
+    ///
+    /// Правила полученной грамматики имеют вид A -> BC, A -> a и, если ε принадлежит языку, S -> ε,
+    /// причём начальный символ S не встречается в правых частях правил.
+    pub fn to_chomsky_normal_form(&self) -> Result<Grammar, GrammarError> {
+        if !self.is_context_free() { return Err(GrammarError::NotContextFree); }
+
+        let mut grammar = self.clone();
+
+        grammar.make_equivalent();
+
+        // Терминалы, входящие в цепочки длины 2 и больше
+        let mut terminals = vec![];
+
+        grammar.rules.iter()
+            .flat_map(|rule| &rule.variants)
+            .filter(|variant| variant.len() >= 2)
+            .flatten()
+            .filter(|sym| sym.is_terminal())
+            .for_each(|sym| {
+                if !terminals.contains(sym) {
+                    terminals.push(sym.clone());
+                }
+            });
+
+        let terminal_non_terminals = terminals.into_iter()
+            .map(|terminal| {
+                let non_terminal = grammar.new_non_terminal();
+
+                grammar.non_terminals.push(non_terminal.clone());
+
+                (terminal, non_terminal)
+            })
+            .collect::<Vec<(Symbol, Symbol)>>();
+
+        grammar.rules.iter_mut()
+            .flat_map(|rule| &mut rule.variants)
+            .filter(|variant| variant.len() >= 2)
+            .flatten()
+            .for_each(|sym| {
+                if let Some((_, non_terminal)) = terminal_non_terminals.iter().find(|(terminal, _)| terminal == sym) {
+                    *sym = non_terminal.clone();
+                }
+            });
+
+        grammar.rules.extend(
+            terminal_non_terminals.iter()
+                .map(|(terminal, non_terminal)| Rule { input: vec![non_terminal.clone()], variants: vec![vec![terminal.clone()]] })
+        );
+
+        // Одинаковые хвосты цепочек выводятся из одного и того же нового нетерминала
+        let mut suffix_non_terminals: Vec<(Vec<Symbol>, Symbol)> = vec![];
+        let mut idx = 0;
+
+        while idx < grammar.rules.len() {
+            let variants = grammar.rules[idx].variants.clone().into_iter()
+                .map(|variant| {
+                    if variant.len() <= 2 { return variant; }
+
+                    let suffix = variant[1..].to_vec();
+
+                    let non_terminal = match suffix_non_terminals.iter().find(|(other, _)| *other == suffix) {
+                        Some((_, non_terminal)) => non_terminal.clone(),
+                        None => {
+                            let non_terminal = grammar.new_non_terminal();
+
+                            grammar.non_terminals.push(non_terminal.clone());
+                            grammar.rules.push(Rule { input: vec![non_terminal.clone()], variants: vec![suffix.clone()] });
+                            suffix_non_terminals.push((suffix, non_terminal.clone()));
+
+                            non_terminal
+                        }
+                    };
+
+                    vec![variant[0].clone(), non_terminal]
+                })
+                .collect::<Vec<Vec<Symbol>>>();
+
+            grammar.rules[idx].variants = variants;
+
+            idx += 1;
+        }
+
+        grammar.grammar_type = Grammar::get_type(&grammar.rules);
+
+        Ok(grammar)
+    }
+
+    /// Приведение грамматики к нормальной форме Грейбах.
+    ///
+    /// Грамматика сначала приводится к нормальной форме Хомского, нетерминалы нумеруются A₁, ..., Aₙ
+    /// в порядке правил. Для каждого i правила Aᵢ -> Aⱼγ, j < i, заменяются подстановкой правил Aⱼ,
+    /// после чего непосредственная левая рекурсия Aᵢ -> Aᵢα | β заменяется правилами Aᵢ -> β | βAᵢ'
+    /// и Aᵢ' -> α | αAᵢ'. Так устраняется и косвенная левая рекурсия. Затем правые части правил,
+    /// начинающиеся с нетерминала, раскрываются подстановкой в обратном порядке.
+    ///
+    /// Правила полученной грамматики имеют вид A -> aB₁...Bₖ и, если ε принадлежит языку, S -> ε.
+    /// Недостижимые после подстановок нетерминалы удаляются.
+    pub fn to_greibach_normal_form(&self) -> Result<Grammar, GrammarError> {
+        let mut grammar = self.to_chomsky_normal_form()?;
+
+        let order = grammar.rules.iter()
+            .map(|rule| rule.input[0].clone())
+            .collect::<Vec<Symbol>>();
+
+        for i in 0..order.len() {
+            for j in 0..i {
+                grammar.substitute_leading_non_terminal(&order[i], &order[j]);
+            }
+
+            grammar.remove_direct_left_recursion(&order[i], false);
+        }
+
+        // Правые части правил Aᵢ начинаются с терминала или с Aⱼ, j > i
+        for i in (0..order.len()).rev() {
+            for non_terminal in &order[i + 1..] {
+                grammar.substitute_leading_non_terminal(&order[i], non_terminal);
+            }
+        }
+
+        // Правые части правил Aᵢ' начинаются с Aⱼ
+        let primed_non_terminals = grammar.rules.iter()
+            .map(|rule| rule.input[0].clone())
+            .filter(|non_terminal| !order.contains(non_terminal))
+            .collect::<Vec<Symbol>>();
+
+        for primed_non_terminal in &primed_non_terminals {
+            for non_terminal in &order {
+                grammar.substitute_leading_non_terminal(primed_non_terminal, non_terminal);
+            }
+        }
+
+        // После подстановок часть нетерминалов может стать недостижимой
+        grammar.remove_unreachable_symbols();
+        grammar.grammar_type = Grammar::get_type(&grammar.rules);
+
+        Ok(grammar)
+    }
+
+    /// Проверка, что правила грамматики имеют вид A -> aB₁...Bₖ или S -> ε,
+    /// где начальный символ S не встречается в правых частях правил.
+    pub fn is_greibach_normal_form(&self) -> bool {
+        let is_start_in_variants = self.rules.iter()
+            .flat_map(|rule| rule.variants.iter().flatten())
+            .any(|sym| *sym == self.starting_non_terminal);
+
+        self.rules.iter()
+            .all(|rule| {
+                rule.input.len() == 1 &&
+                rule.input[0].is_non_terminal() &&
+                rule.variants.iter().all(|variant| match variant.as_slice() {
+                    [e] if e.is_empty() => rule.input[0] == self.starting_non_terminal && !is_start_in_variants,
+                    [a, rest @ ..] => a.is_terminal() && rest.iter().all(Symbol::is_non_terminal),
+                    [] => false
+                })
+            })
+    }
+
+    /// Проверка, что правила грамматики имеют вид A -> BC, A -> a или S -> ε,
+    /// где начальный символ S не встречается в правых частях правил.
+    pub fn is_chomsky_normal_form(&self) -> bool {
+        let is_start_in_variants = self.rules.iter()
+            .flat_map(|rule| rule.variants.iter().flatten())
+            .any(|sym| *sym == self.starting_non_terminal);
+
+        self.rules.iter()
+            .all(|rule| {
+                rule.input.len() == 1 &&
+                rule.input[0].is_non_terminal() &&
+                rule.variants.iter().all(|variant| match variant.as_slice() {
+                    [a] if a.is_terminal() => true,
+                    [e] if e.is_empty() => rule.input[0] == self.starting_non_terminal && !is_start_in_variants,
+                    [b, c] => b.is_non_terminal() && c.is_non_terminal(),
+                    _ => false
+                })
+            })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use crate::{generate, grammar::{Grammar, GrammarError, GrammarType, Symbol}};
+
+    /// Цепочки языка грамматики длины не больше max_len, полученные левосторонними выводами.
+    /// Грамматика не должна содержать ε-правил, кроме S -> ε.
+    fn words(grammar: &Grammar, max_len: usize) -> Vec<String> {
+        let mut words = vec![];
+        let mut visited = HashSet::new();
+        let mut forms = vec![vec![grammar.starting_non_terminal.clone()]];
+
+        while let Some(form) = forms.pop() {
+            let Some(idx) = form.iter().position(Symbol::is_non_terminal) else {
+                words.push(form.iter().filter(|sym| !sym.is_empty()).map(Symbol::name).collect::<String>());
+                continue;
+            };
+
+            grammar.rules.iter()
+                .filter(|rule| rule.input[0] == form[idx])
+                .flat_map(|rule| &rule.variants)
+                .map(|variant| 
+                    form[..idx].iter()
+                        .chain(variant)
+                        .chain(&form[idx + 1..])
+                        .cloned()
+                        .collect::<Vec<Symbol>>()
+                )
+                .filter(|form| form.len() <= max_len)
+                .for_each(|form| {
+                    if visited.insert(form.clone()) {
+                        forms.push(form);
+                    }
+                });
+        }
+
+        words.sort();
+        words.dedup();
+
+        words
+    }
+
+    #[test]
+    fn test_chomsky_normal_form() {
+        let grammar = generate!{
+            {'+', '*', '(', ')', 'a'},
+            {'E', 'T', 'F'},
+            {
+                "E" -> "E+T" | "T",
+                "T" -> "T*F" | "F",
+                "F" -> "(E)" | "a"
+            },
+            'E'
+        }.expect("Failed to generate grammar");
+
+        println!("{}", grammar);
+
+        assert!(!grammar.is_chomsky_normal_form(), "Grammar with unit rules should not be in Chomsky normal form");
+
+        let normal_form = grammar.to_chomsky_normal_form().expect("Failed to convert grammar");
+
+        println!("{}", normal_form);
+        println!("{}", normal_form.grammar_type);
+
+        assert!(normal_form.is_chomsky_normal_form(), "Grammar is not in Chomsky normal form: {}", normal_form);
+
+        let rules = normal_form.rules.iter().map(|rule| rule.to_string()).collect::<Vec<String>>();
+
+        assert_eq!(rules, vec![
+            "E -> EG | TH | CI | a",
+            "T -> TH | CI | a",
+            "F -> CI | a",
+            "A -> +",
+            "B -> *",
+            "C -> (",
+            "D -> )",
+            "G -> AT",
+            "H -> BF",
+            "I -> ED",
+        ]);
+        assert_eq!(normal_form.starting_non_terminal, 'E');
+        assert_eq!(words(&normal_form, 7), words(&grammar, 7));
+
+        let grammar = generate!{
+            {'a', 'b'},
+            {'S'},
+            {
+                "S" -> "aSb" | "ε"
+            },
+            'S'
+        }.expect("Failed to generate grammar");
+
+        let normal_form = grammar.to_chomsky_normal_form().expect("Failed to convert grammar");
+
+        println!("{}", normal_form);
+
+        assert!(normal_form.is_chomsky_normal_form(), "Grammar is not in Chomsky normal form: {}", normal_form);
+        assert_eq!(normal_form.starting_non_terminal, 'A');
+
+        let rules = normal_form.rules.iter().map(|rule| rule.to_string()).collect::<Vec<String>>();
+
+        assert_eq!(rules, vec![
+            "S -> BD | BC",
+            "A -> ε | BD | BC",
+            "B -> a",
+            "C -> b",
+            "D -> SC",
+        ]);
+
+        let grammar = generate!{
+            {'a'},
+            {'S'},
+            {
+                "S" -> "aS" | "a",
+                "SS" -> "aa"
+            },
+            'S'
+        }.expect("Failed to generate grammar");
+
+        assert_eq!(grammar.grammar_type, GrammarType::ContextDependent);
+        assert_eq!(grammar.to_chomsky_normal_form().err(), Some(GrammarError::NotContextFree));
+    }
+
+    #[test]
+    fn test_greibach_normal_form() {
+        let grammar = generate!{
+            {'+', '*', '(', ')', 'a'},
+            {'E', 'T', 'F'},
+            {
+                "E" -> "E+T" | "T",
+                "T" -> "T*F" | "F",
+                "F" -> "(E)" | "a"
+            },
+            'E'
+        }.expect("Failed to generate grammar");
+
+        assert!(!grammar.is_greibach_normal_form(), "Left-recursive grammar should not be in Greibach normal form");
+
+        let normal_form = grammar.to_greibach_normal_form().expect("Failed to convert grammar");
+
+        println!("{}", normal_form);
+        println!("{}", normal_form.grammar_type);
+
+        assert!(normal_form.is_greibach_normal_form(), "Grammar is not in Greibach normal form: {}", normal_form);
+        assert_eq!(words(&normal_form, 7), words(&grammar, 7));
+
+        // Косвенная левая рекурсия: S -> Ab -> Sab
+        let grammar = generate!{
+            {'a', 'b', 'c'},
+            {'S', 'A'},
+            {
+                "S" -> "Ab" | "c",
+                "A" -> "Sa" | "a"
+            },
+            'S'
+        }.expect("Failed to generate grammar");
+
+        let normal_form = grammar.to_greibach_normal_form().expect("Failed to convert grammar");
+
+        println!("{}", normal_form);
+
+        assert!(normal_form.is_greibach_normal_form(), "Grammar is not in Greibach normal form: {}", normal_form);
+        assert_eq!(words(&normal_form, 8), words(&grammar, 8));
+
+        let grammar = generate!{
+            {'a', 'b'},
+            {'S'},
+            {
+                "S" -> "aSb" | "ε"
+            },
+            'S'
+        }.expect("Failed to generate grammar");
+
+        let normal_form = grammar.to_greibach_normal_form().expect("Failed to convert grammar");
+
+        println!("{}", normal_form);
+
+        assert!(normal_form.is_greibach_normal_form(), "Grammar is not in Greibach normal form: {}", normal_form);
+        assert_eq!(words(&normal_form, 6), vec!["", "aaabbb", "aabb", "ab"]);
+    }
+}
@@ -0,0 +1,501 @@
+use std::collections::HashSet;
+
+use super::{Grammar, GrammarError, Rule, Symbol};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TokenKind {
+    /// Имя без кавычек: нетерминал, если для него есть правило, иначе терминал.
+    Word(String),
+    /// Терминал в кавычках.
+    Quoted(String),
+    /// Нетерминал в угловых скобках.
+    Bracketed(String),
+    /// `->` или `::=`
+    Arrow,
+    Bar,
+    Semicolon,
+    Open(char),
+    Close(char),
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    kind: TokenKind,
+    text: String,
+    line: usize,
+    column: usize,
+}
+
+/// Элемент правой части правила до раскрытия конструкций EBNF.
+enum Item {
+    Symbol(TokenKind),
+    Empty,
+    /// Скобка, в которой записана группа, и её альтернативы.
+    Group(char, Vec<Vec<Item>>),
+}
+
+/// Разбиение текста грамматики на лексемы.
+struct Lexer {
+    symbols: Vec<char>,
+    position: usize,
+    line: usize,
+    column: usize,
+}
+
+impl Lexer {
+    const SPECIAL_SYMBOLS: [char; 9] = ['|', ';', '(', ')', '[', ']', '{', '}', '"'];
+
+    fn new(text: &str) -> Self {
+        Self {
+            symbols: text.chars().collect(),
+            position: 0,
+            line: 1,
+            column: 1
+        }
+    }
+
+    fn peek(&self, offset: usize) -> Option<char> {
+        self.symbols.get(self.position + offset).cloned()
+    }
+
+    fn starts_with(&self, text: &str) -> bool {
+        text.chars().enumerate().all(|(offset, symbol)| self.peek(offset) == Some(symbol))
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let symbol = self.peek(0)?;
+        self.position += 1;
+
+        if symbol == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+
+        Some(symbol)
+    }
+
+    fn is_comment_start(&self) -> bool {
+        self.starts_with("#") || self.starts_with("//")
+    }
+
+    fn is_word_end(&self) -> bool {
+        match self.peek(0) {
+            None => true,
+            Some(symbol) => symbol.is_whitespace() ||
+                Self::SPECIAL_SYMBOLS.contains(&symbol) ||
+                self.starts_with("->") || self.starts_with("::=") ||
+                self.is_comment_start()
+        }
+    }
+
+    /// Нетерминал вида `<name>`: закрывающая скобка должна быть в той же строке, пробелы внутри не допускаются.
+    fn bracketed_length(&self) -> Option<usize> {
+        let length = self.symbols[self.position + 1..].iter()
+            .position(|symbol| *symbol == '>' || symbol.is_whitespace() || *symbol == '<')?;
+
+        (self.peek(length + 1) == Some('>') && length > 0).then_some(length + 2)
+    }
+
+    fn tokenize(mut self) -> Result<Vec<Token>, GrammarError> {
+        let mut tokens = vec![];
+
+        while let Some(symbol) = self.peek(0) {
+            if symbol.is_whitespace() {
+                self.next();
+                continue;
+            }
+
+            if self.is_comment_start() {
+                while self.peek(0).is_some_and(|symbol| symbol != '\n') {
+                    self.next();
+                }
+
+                continue;
+            }
+
+            let (line, column) = (self.line, self.column);
+            let start = self.position;
+
+            let kind = if self.starts_with("->") || self.starts_with("::=") {
+                let length = if symbol == '-' { 2 } else { 3 };
+                (0..length).for_each(|_| { self.next(); });
+
+                TokenKind::Arrow
+            } else if symbol == '"' || symbol == '\'' {
+                self.next();
+
+                let mut name = String::new();
+
+                loop {
+                    match self.next() {
+                        Some(quote) if quote == symbol => break,
+                        Some('\n') | None => return Err(GrammarError::UnterminatedString { line, column }),
+                        Some(symbol) => name.push(symbol)
+                    }
+                }
+
+                TokenKind::Quoted(name)
+            } else if let Some(length) = (symbol == '<').then(|| self.bracketed_length()).flatten() {
+                (0..length).for_each(|_| { self.next(); });
+
+                TokenKind::Bracketed(self.symbols[start + 1..start + length - 1].iter().collect())
+            } else if Self::SPECIAL_SYMBOLS.contains(&symbol) {
+                self.next();
+
+                match symbol {
+                    '|' => TokenKind::Bar,
+                    ';' => TokenKind::Semicolon,
+                    '(' | '[' | '{' => TokenKind::Open(symbol),
+                    _ => TokenKind::Close(symbol)
+                }
+            } else {
+                self.next();
+
+                while !self.is_word_end() {
+                    self.next();
+                }
+
+                TokenKind::Word(self.symbols[start..self.position].iter().collect())
+            };
+
+            tokens.push(Token {
+                kind,
+                text: self.symbols[start..self.position].iter().collect(),
+                line,
+                column
+            });
+        }
+
+        tokens.push(Token { kind: TokenKind::Semicolon, text: String::new(), line: self.line, column: self.column });
+
+        Ok(tokens)
+    }
+}
+
+/// Разбор грамматики в нотации BNF/EBNF методом рекурсивного спуска.
+///
+/// rule := name ('->' | '::=') alternatives ';'?
+/// alternatives := sequence ('|' sequence)*
+/// sequence := (name | "terminal" | <non-terminal> | ε | '(' alternatives ')' | '[' alternatives ']' | '{' alternatives '}')*
+pub(super) struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    pub(super) fn new(text: &str) -> Result<Self, GrammarError> {
+        Ok(Self {
+            tokens: Lexer::new(text).tokenize()?,
+            position: 0
+        })
+    }
+
+    pub(super) fn parse(mut self) -> Result<Grammar, GrammarError> {
+        let mut parsed_rules = vec![];
+
+        while !self.is_end() {
+            let name = match self.next().kind {
+                TokenKind::Word(name) | TokenKind::Bracketed(name) if name != Symbol::Empty.name() => name,
+                _ => return Err(self.unexpected_token(self.position - 1))
+            };
+
+            if self.next().kind != TokenKind::Arrow {
+                return Err(self.unexpected_token(self.position - 1));
+            }
+
+            let alternatives = self.parse_alternatives()?;
+
+            match self.peek().kind {
+                TokenKind::Semicolon => { self.position += 1; },
+                _ if self.is_rule_start() => {},
+                _ => return Err(self.unexpected_token(self.position))
+            }
+
+            parsed_rules.push((name, alternatives));
+        }
+
+        let mut desugarer = Desugarer::new(&self.tokens, &parsed_rules);
+
+        for (name, alternatives) in parsed_rules {
+            desugarer.add_rule(name, alternatives);
+        }
+
+        desugarer.into_grammar()
+    }
+
+    fn peek(&self) -> &Token {
+        &self.tokens[self.position.min(self.tokens.len() - 1)]
+    }
+
+    fn next(&mut self) -> Token {
+        let token = self.peek().clone();
+        self.position += 1;
+
+        token
+    }
+
+    /// Последняя лексема - искусственная `;` в конце текста.
+    fn is_end(&self) -> bool {
+        self.position >= self.tokens.len() - 1
+    }
+
+    fn is_rule_start(&self) -> bool {
+        matches!(self.peek().kind, TokenKind::Word(_) | TokenKind::Bracketed(_)) &&
+            self.tokens.get(self.position + 1).is_some_and(|token| token.kind == TokenKind::Arrow)
+    }
+
+    fn unexpected_token(&self, position: usize) -> GrammarError {
+        let token = &self.tokens[position.min(self.tokens.len() - 1)];
+
+        if position >= self.tokens.len() - 1 {
+            GrammarError::UnexpectedEnd { line: token.line, column: token.column }
+        } else {
+            GrammarError::UnexpectedToken { line: token.line, column: token.column, token: token.text.clone() }
+        }
+    }
+
+    fn parse_alternatives(&mut self) -> Result<Vec<Vec<Item>>, GrammarError> {
+        let mut alternatives = vec![self.parse_sequence()?];
+
+        while self.peek().kind == TokenKind::Bar {
+            self.position += 1;
+
+            alternatives.push(self.parse_sequence()?);
+        }
+
+        Ok(alternatives)
+    }
+
+    fn parse_sequence(&mut self) -> Result<Vec<Item>, GrammarError> {
+        let mut sequence = vec![];
+
+        loop {
+            if self.is_end() || self.is_rule_start() { break; }
+
+            match self.peek().kind.clone() {
+                TokenKind::Bar | TokenKind::Semicolon | TokenKind::Close(_) => break,
+                TokenKind::Arrow => return Err(self.unexpected_token(self.position)),
+                TokenKind::Word(name) if name == Symbol::Empty.name() => {
+                    self.position += 1;
+                    sequence.push(Item::Empty);
+                },
+                TokenKind::Open(bracket) => {
+                    self.position += 1;
+
+                    let alternatives = self.parse_alternatives()?;
+
+                    let closing_bracket = match bracket {
+                        '(' => ')',
+                        '[' => ']',
+                        _ => '}'
+                    };
+
+                    if self.peek().kind != TokenKind::Close(closing_bracket) {
+                        return Err(self.unexpected_token(self.position));
+                    }
+
+                    self.position += 1;
+                    sequence.push(Item::Group(bracket, alternatives));
+                },
+                kind => {
+                    self.position += 1;
+                    sequence.push(Item::Symbol(kind));
+                }
+            }
+        }
+
+        Ok(sequence)
+    }
+}
+
+/// Раскрытие конструкций EBNF через новые нетерминалы:
+///
+/// (α | β) -> A' -> α | β;
+/// [α] -> A' -> α | ε;
+/// {α} -> A' -> αA' | ε,
+///
+/// где A - нетерминал левой части правила, в котором встретилась конструкция.
+struct Desugarer {
+    rule_names: HashSet<String>,
+    used_names: HashSet<String>,
+    terminals: Vec<Symbol>,
+    non_terminals: Vec<Symbol>,
+    rules: Vec<Rule>,
+}
+
+impl Desugarer {
+    fn new(tokens: &[Token], parsed_rules: &[(String, Vec<Vec<Item>>)]) -> Self {
+        let rule_names = parsed_rules.iter()
+            .map(|(name, _)| name.clone())
+            .collect::<HashSet<String>>();
+
+        let used_names = tokens.iter()
+            .filter_map(|token| match &token.kind {
+                TokenKind::Word(name) | TokenKind::Quoted(name) | TokenKind::Bracketed(name) => Some(name.clone()),
+                _ => None
+            })
+            .collect();
+
+        let mut desugarer = Self {
+            rule_names,
+            used_names,
+            terminals: vec![],
+            non_terminals: vec![],
+            rules: vec![]
+        };
+
+        parsed_rules.iter()
+            .for_each(|(name, _)| desugarer.add_non_terminal(Symbol::non_terminal(name.clone())));
+
+        desugarer
+    }
+
+    fn add_non_terminal(&mut self, symbol: Symbol) {
+        if !self.non_terminals.contains(&symbol) {
+            self.non_terminals.push(symbol);
+        }
+    }
+
+    fn fresh_non_terminal(&mut self, name: &str) -> Symbol {
+        let mut fresh_name = format!("{}'", name);
+
+        while self.used_names.contains(&fresh_name) {
+            fresh_name.push('\'');
+        }
+
+        self.used_names.insert(fresh_name.clone());
+
+        let symbol = Symbol::non_terminal(fresh_name);
+        self.add_non_terminal(symbol.clone());
+
+        symbol
+    }
+
+    /// Правило добавляется к уже существующему правилу с той же левой частью.
+    fn push_rule(&mut self, input: Symbol, variants: Vec<Vec<Symbol>>) {
+        let rule = match self.rules.iter_mut().find(|rule| rule.input == [input.clone()]) {
+            Some(rule) => rule,
+            None => {
+                self.rules.push(Rule { input: vec![input], variants: vec![] });
+                self.rules.last_mut().unwrap()
+            }
+        };
+
+        variants.into_iter()
+            .for_each(|variant| {
+                if !rule.variants.contains(&variant) {
+                    rule.variants.push(variant);
+                }
+            });
+    }
+
+    fn add_rule(&mut self, name: String, alternatives: Vec<Vec<Item>>) {
+        let input = Symbol::non_terminal(name.clone());
+
+        // Правило должно идти раньше правил, полученных при раскрытии его конструкций EBNF
+        self.push_rule(input.clone(), vec![]);
+
+        let variants = alternatives.into_iter()
+            .map(|sequence| self.lower_sequence(&name, sequence))
+            .collect();
+
+        self.push_rule(input, variants);
+    }
+
+    fn lower_sequence(&mut self, name: &str, sequence: Vec<Item>) -> Vec<Symbol> {
+        let mut symbols = vec![];
+
+        for item in sequence {
+            match item {
+                Item::Empty => {},
+                Item::Symbol(TokenKind::Quoted(name)) => symbols.push(self.terminal(name)),
+                Item::Symbol(TokenKind::Bracketed(name)) => {
+                    let symbol = Symbol::non_terminal(name);
+                    self.add_non_terminal(symbol.clone());
+
+                    symbols.push(symbol);
+                },
+                Item::Symbol(TokenKind::Word(word)) => {
+                    if self.rule_names.contains(&word) {
+                        symbols.push(Symbol::non_terminal(word));
+                    } else {
+                        symbols.push(self.terminal(word));
+                    }
+                },
+                Item::Symbol(_) => unreachable!("Only symbols are parsed as sequence items"),
+                Item::Group('(', mut alternatives) if alternatives.len() == 1 => {
+                    let sequence = alternatives.remove(0);
+                    let inner = self.lower_sequence(name, sequence);
+
+                    symbols.extend(inner.into_iter().filter(|symbol| !symbol.is_empty()));
+                },
+                Item::Group(bracket, alternatives) => {
+                    let non_terminal = self.fresh_non_terminal(name);
+
+                    let mut variants = alternatives.into_iter()
+                        .map(|sequence| {
+                            let mut variant = self.lower_sequence(non_terminal.name(), sequence);
+
+                            if bracket == '{' {
+                                variant.retain(|symbol| !symbol.is_empty());
+                                variant.push(non_terminal.clone());
+                            }
+
+                            variant
+                        })
+                        .collect::<Vec<Vec<Symbol>>>();
+
+                    if bracket != '(' {
+                        variants.push(vec![Symbol::Empty]);
+                    }
+
+                    self.push_rule(non_terminal.clone(), variants);
+
+                    symbols.push(non_terminal);
+                }
+            }
+        }
+
+        if symbols.is_empty() {
+            symbols.push(Symbol::Empty);
+        }
+
+        symbols
+    }
+
+    fn terminal(&mut self, name: String) -> Symbol {
+        let symbol = Symbol::terminal(name);
+
+        if !self.terminals.contains(&symbol) {
+            self.terminals.push(symbol.clone());
+        }
+
+        symbol
+    }
+
+    fn into_grammar(self) -> Result<Grammar, GrammarError> {
+        let starting_non_terminal = match self.non_terminals.first() {
+            Some(symbol) => symbol.clone(),
+            None => return Err(GrammarError::MissingStartingNonTerminalSymbol)
+        };
+
+        if self.terminals.iter()
+            .any(|sym| self.non_terminals.iter().any(|non_terminal| non_terminal.name() == sym.name())) 
+        {
+            return Err(GrammarError::OverlappingSymbols);
+        }
+
+        // Вид символов уже известен, поэтому правила не проходят повторную классификацию в `Grammar::new`
+        let grammar_type = Grammar::get_type(&self.rules);
+
+        Ok(Grammar {
+            terminals: self.terminals,
+            non_terminals: self.non_terminals,
+            rules: self.rules,
+            starting_non_terminal,
+            grammar_type
+        })
+    }
+}
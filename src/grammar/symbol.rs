@@ -0,0 +1,136 @@
+use std::fmt::Display;
+
+/// Символ грамматики: терминал, нетерминал или пустая цепочка ε.
+///
+/// Имя символа - произвольная строка, поэтому допустимы нетерминалы вида `Expr`, `Term'`
+/// и терминалы вида `id`, `num`, `==`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Symbol {
+    /// Терминальный символ, a ∈ Vᴛ.
+    Terminal(String),
+    /// Нетерминальный символ, A ∈ Vɴ.
+    NonTerminal(String),
+    /// Пустая цепочка ε.
+    Empty,
+    /// Маркер конца входной цепочки $, используемый в множествах FOLLOW и таблицах разбора.
+    End,
+}
+
+impl Symbol {
+    const EMPTY_SEQUENCE: &'static str = "ε";
+    const END_MARKER: &'static str = "$";
+
+    pub fn terminal(name: impl Into<String>) -> Self {
+        Symbol::Terminal(name.into())
+    }
+
+    pub fn non_terminal(name: impl Into<String>) -> Self {
+        Symbol::NonTerminal(name.into())
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            Symbol::Terminal(name) | Symbol::NonTerminal(name) => name,
+            Symbol::Empty => Self::EMPTY_SEQUENCE,
+            Symbol::End => Self::END_MARKER
+        }
+    }
+
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Symbol::Terminal(_))
+    }
+
+    pub fn is_non_terminal(&self) -> bool {
+        matches!(self, Symbol::NonTerminal(_))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        matches!(self, Symbol::Empty)
+    }
+
+    pub fn is_end(&self) -> bool {
+        matches!(self, Symbol::End)
+    }
+
+    /// Разбиение строки на цепочку символов.
+    ///
+    /// Если строка содержит пробелы, символами считаются слова (`"Expr + Term"`),
+    /// иначе - отдельные знаки (`"E+T"`). Вид символа определяется по имени
+    /// (см. `From<String>`) и уточняется в `Grammar::new` по множествам Vᴛ и Vɴ.
+    /// Слитная запись имени символа (`"Term"`) снова становится одним символом
+    /// в `Grammar::from_written_rules`.
+    pub fn split(text: &str) -> Vec<Symbol> {
+        if text.contains(char::is_whitespace) {
+            text.split_whitespace()
+                .map(Symbol::from)
+                .collect()
+        } else {
+            text.chars()
+                .map(Symbol::from)
+                .collect()
+        }
+    }
+
+    /// Нужны ли кавычки, чтобы имя терминала не смешивалось с записью правил
+    /// и не читалось как нетерминал (имя с заглавной буквы).
+    fn needs_quotes(name: &str) -> bool {
+        name.is_empty() ||
+        name.starts_with(char::is_uppercase) ||
+        [Self::EMPTY_SEQUENCE, "->", "::="].contains(&name) ||
+        name.contains(|ch: char| ch.is_whitespace() || ['"', '|', ',', '{', '}'].contains(&ch))
+    }
+}
+
+/// Имя ε означает пустую цепочку, имя с заглавной буквы - нетерминал, остальные - терминалы.
+impl From<String> for Symbol {
+    fn from(name: String) -> Self {
+        if name == Self::EMPTY_SEQUENCE {
+            Symbol::Empty
+        } else if name.starts_with(char::is_uppercase) {
+            Symbol::NonTerminal(name)
+        } else {
+            Symbol::Terminal(name)
+        }
+    }
+}
+
+impl From<&str> for Symbol {
+    fn from(name: &str) -> Self {
+        Symbol::from(name.to_string())
+    }
+}
+
+impl From<char> for Symbol {
+    fn from(name: char) -> Self {
+        Symbol::from(name.to_string())
+    }
+}
+
+impl PartialEq<str> for Symbol {
+    fn eq(&self, other: &str) -> bool {
+        self.name() == other
+    }
+}
+
+impl PartialEq<&str> for Symbol {
+    fn eq(&self, other: &&str) -> bool {
+        self.name() == *other
+    }
+}
+
+impl PartialEq<char> for Symbol {
+    fn eq(&self, other: &char) -> bool {
+        let mut chars = self.name().chars();
+
+        chars.next() == Some(*other) && chars.next().is_none()
+    }
+}
+
+impl Display for Symbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Symbol::Terminal(name) if Self::needs_quotes(name) => write!(f, "{:?}", name),
+            _ => write!(f, "{}", self.name())
+        }
+    }
+}
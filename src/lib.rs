@@ -4,4 +4,6 @@ pub mod grammar;
 
 pub mod fsa;
 
+pub mod regex;
+
 mod tasks;
\ No newline at end of file
@@ -0,0 +1,342 @@
+mod parser;
+pub use parser::RegexError;
+
+mod thompson;
+
+mod state_elimination;
+
+use std::{fmt::Display, str::FromStr};
+
+/// Регулярное выражение над алфавитом символов `char`.
+/// 
+/// Синтаксис (в порядке убывания приоритета):
+/// 
+/// ```text
+///     a, \*, ε, ∅, [abc], [a-z] - символ, экранированный символ, пустая цепочка, пустое множество, класс символов;
+/// 
+///     (r) - группировка;
+/// 
+///     r*, r+, r? - итерация, усечённая итерация, необязательность;
+/// 
+///     rs - конкатенация;
+/// 
+///     r|s - объединение.
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Regex {
+    /// Пустое множество ∅.
+    Empty,
+    /// Пустая цепочка ε.
+    Epsilon,
+    Symbol(char),
+    /// Класс символов, символы отсортированы и не повторяются.
+    Class(Vec<char>),
+    Concat(Box<Regex>, Box<Regex>),
+    Union(Box<Regex>, Box<Regex>),
+    /// Итерация r*.
+    Star(Box<Regex>),
+    /// Усечённая итерация r+.
+    Plus(Box<Regex>),
+    /// Необязательность r?.
+    Optional(Box<Regex>),
+}
+
+impl Regex {
+    pub const EMPTY_SEQUENCE: char = 'ε';
+    pub const EMPTY_SET: char = '∅';
+
+    /// Символы, которые нужно экранировать, чтобы использовать их как обычные.
+    pub(crate) const SPECIAL_SYMBOLS: [char; 12] = ['|', '*', '+', '?', '(', ')', '[', ']', '\\', ' ', Self::EMPTY_SEQUENCE, Self::EMPTY_SET];
+
+    pub fn parse(regex: &str) -> Result<Self, RegexError> {
+        parser::Parser::new(regex).parse()
+    }
+
+    /// Конкатенация с упрощениями ∅·r = r·∅ = ∅ и ε·r = r·ε = r.
+    pub fn concat(left: Regex, right: Regex) -> Regex {
+        match (left, right) {
+            (Regex::Empty, _) | (_, Regex::Empty) => Regex::Empty,
+            (Regex::Epsilon, regex) | (regex, Regex::Epsilon) => regex,
+            (left, right) => Regex::Concat(Box::new(left), Box::new(right))
+        }
+    }
+
+    /// Объединение с упрощениями ∅|r = r|∅ = r и r|r = r.
+    pub fn union(left: Regex, right: Regex) -> Regex {
+        match (left, right) {
+            (Regex::Empty, regex) | (regex, Regex::Empty) => regex,
+            (left, right) if left == right => left,
+            (left, right) => Regex::Union(Box::new(left), Box::new(right))
+        }
+    }
+
+    /// Итерация с упрощениями ∅* = ε* = ε и (r*)* = (r+)* = (r?)* = r*.
+    pub fn star(regex: Regex) -> Regex {
+        match regex {
+            Regex::Empty | Regex::Epsilon => Regex::Epsilon,
+            Regex::Star(regex) | Regex::Plus(regex) | Regex::Optional(regex) => Regex::star(*regex),
+            regex => Regex::Star(Box::new(regex))
+        }
+    }
+
+    /// Все символы алфавита, встречающиеся в выражении, в порядке возрастания.
+    pub fn symbols(&self) -> Vec<char> {
+        let mut symbols = vec![];
+
+        self.collect_symbols(&mut symbols);
+
+        symbols.sort();
+        symbols.dedup();
+
+        symbols
+    }
+
+    fn collect_symbols(&self, symbols: &mut Vec<char>) {
+        match self {
+            Regex::Empty | Regex::Epsilon => {},
+            Regex::Symbol(symbol) => symbols.push(*symbol),
+            Regex::Class(class) => symbols.extend(class),
+            Regex::Concat(left, right) | Regex::Union(left, right) => {
+                left.collect_symbols(symbols);
+                right.collect_symbols(symbols);
+            },
+            Regex::Star(regex) | Regex::Plus(regex) | Regex::Optional(regex) => regex.collect_symbols(symbols),
+        }
+    }
+
+    /// Приоритет операции, используется для расстановки скобок при выводе.
+    fn precedence(&self) -> u8 {
+        match self {
+            Regex::Union(..) => 0,
+            Regex::Concat(..) => 1,
+            Regex::Star(_) | Regex::Plus(_) | Regex::Optional(_) => 2,
+            _ => 3
+        }
+    }
+
+    fn fmt_operand(&self, f: &mut std::fmt::Formatter<'_>, precedence: u8) -> std::fmt::Result {
+        if self.precedence() < precedence {
+            write!(f, "({})", self)
+        } else {
+            write!(f, "{}", self)
+        }
+    }
+}
+
+fn fmt_symbol(f: &mut std::fmt::Formatter<'_>, symbol: char, special_symbols: &[char]) -> std::fmt::Result {
+    if special_symbols.contains(&symbol) {
+        write!(f, "\\{}", symbol)
+    } else {
+        write!(f, "{}", symbol)
+    }
+}
+
+impl Display for Regex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Regex::Empty => write!(f, "{}", Self::EMPTY_SET),
+            Regex::Epsilon => write!(f, "{}", Self::EMPTY_SEQUENCE),
+            Regex::Symbol(symbol) => fmt_symbol(f, *symbol, &Self::SPECIAL_SYMBOLS),
+            Regex::Class(class) => {
+                write!(f, "[")?;
+
+                for symbol in class {
+                    fmt_symbol(f, *symbol, &['[', ']', '\\', '-', ' '])?;
+                }
+
+                write!(f, "]")
+            },
+            Regex::Concat(left, right) => {
+                left.fmt_operand(f, 1)?;
+                right.fmt_operand(f, 2)
+            },
+            Regex::Union(left, right) => {
+                left.fmt_operand(f, 0)?;
+                write!(f, "|")?;
+                right.fmt_operand(f, 1)
+            },
+            Regex::Star(regex) => {
+                regex.fmt_operand(f, 3)?;
+                write!(f, "*")
+            },
+            Regex::Plus(regex) => {
+                regex.fmt_operand(f, 3)?;
+                write!(f, "+")
+            },
+            Regex::Optional(regex) => {
+                regex.fmt_operand(f, 3)?;
+                write!(f, "?")
+            },
+        }
+    }
+}
+
+impl FromStr for Regex {
+    type Err = RegexError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Regex::parse(s)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{fsa::{Dfa, Nfa}, transitions};
+
+    use super::{Regex, RegexError};
+
+    #[test]
+    fn test_parse_regex() {
+        let regex = Regex::parse("(a|b)*abb").expect("Failed to parse regex");
+
+        println!("{}", regex);
+        println!("{:?}", regex);
+
+        assert_eq!(regex.to_string(), "(a|b)*abb");
+        assert_eq!(regex.symbols(), vec!['a', 'b']);
+
+        let regex = Regex::parse("a+b?|[x-z0]*ε").expect("Failed to parse regex");
+
+        println!("{}", regex);
+
+        assert_eq!(regex, Regex::Union(
+            Box::new(Regex::Concat(
+                Box::new(Regex::Plus(Box::new(Regex::Symbol('a')))),
+                Box::new(Regex::Optional(Box::new(Regex::Symbol('b'))))
+            )),
+            Box::new(Regex::Concat(
+                Box::new(Regex::Star(Box::new(Regex::Class(vec!['0', 'x', 'y', 'z'])))),
+                Box::new(Regex::Epsilon)
+            ))
+        ));
+
+        assert_eq!(Regex::parse(&regex.to_string()).unwrap(), regex, "Printed regex should parse back into the same tree");
+
+        let regex = Regex::parse(r"a(b(cd))\*").expect("Failed to parse regex");
+
+        assert_eq!(regex.to_string(), r"a(b(cd))\*");
+        assert_eq!(Regex::parse(&regex.to_string()).unwrap(), regex);
+    }
+
+    #[test]
+    fn test_parse_regex_errors() {
+        assert_eq!(Regex::parse("(ab"), Err(RegexError::UnexpectedEnd));
+        assert_eq!(Regex::parse("ab)"), Err(RegexError::UnexpectedSymbol { position: 2, symbol: ')' }));
+        assert_eq!(Regex::parse("*a"), Err(RegexError::UnexpectedSymbol { position: 0, symbol: '*' }));
+        assert_eq!(Regex::parse("[z-a]"), Err(RegexError::InvalidRange { position: 1 }));
+        assert_eq!(Regex::parse("a[]"), Err(RegexError::EmptyClass { position: 1 }));
+        assert_eq!(Regex::parse("a |  )"), Err(RegexError::UnexpectedSymbol { position: 5, symbol: ')' }));
+        assert_eq!(Regex::parse("a |  *"), Err(RegexError::UnexpectedSymbol { position: 5, symbol: '*' }));
+        assert_eq!(Regex::parse("a  []"), Err(RegexError::EmptyClass { position: 3 }));
+        assert_eq!(Regex::parse("a|"), Ok(Regex::Union(Box::new(Regex::Symbol('a')), Box::new(Regex::Epsilon))));
+    }
+
+    #[test]
+    fn test_thompson_construction() {
+        let regex = Regex::parse("(a|b)*abb").expect("Failed to parse regex");
+
+        let nfa: Nfa<u32, char> = regex.to_nfa();
+
+        assert!(nfa.accepts(&['a', 'a', 'b', 'b']));
+        assert!(!nfa.accepts(&['a', 'b']));
+
+        println!("{}", nfa);
+        println!("{}", nfa.transitions);
+        println!("{:?}", nfa.epsilon_transitions);
+
+        assert_eq!(nfa.inputs, vec!['a', 'b']);
+        assert_eq!(nfa.starting_states.len(), 1);
+        assert_eq!(nfa.closing_states.len(), 1);
+
+        let mut dfa = nfa.to_deterministic();
+        dfa.minify();
+
+        println!("{}", dfa);
+        println!("{}", dfa.transitions);
+
+        assert_eq!(dfa.states.len(), 4, "Minimal DFA for (a|b)*abb has 4 states");
+        assert_eq!(dfa.closing_states.len(), 1);
+
+        assert!(dfa.accepts(&['a', 'b', 'b']));
+        assert!(dfa.accepts(&['b', 'a', 'b', 'a', 'b', 'b']));
+        assert!(!dfa.accepts(&['a', 'b', 'b', 'a']));
+
+        let nfa: Nfa<char, char> = Regex::parse("∅|ab").unwrap().to_nfa();
+        let mut dfa = nfa.to_deterministic();
+        dfa.minify();
+
+        assert_eq!(dfa.states.len(), 3);
+    }
+
+    #[test]
+    fn test_simplification() {
+        let a = Regex::Symbol('a');
+
+        assert_eq!(Regex::concat(Regex::Epsilon, a.clone()), a);
+        assert_eq!(Regex::concat(a.clone(), Regex::Empty), Regex::Empty);
+        assert_eq!(Regex::union(Regex::Empty, a.clone()), a);
+        assert_eq!(Regex::union(a.clone(), a.clone()), a);
+        assert_eq!(Regex::star(Regex::star(a.clone())), Regex::star(a.clone()));
+        assert_eq!(Regex::star(Regex::Empty), Regex::Epsilon);
+    }
+
+    #[test]
+    fn test_state_elimination() {
+        let transitions = transitions!{
+            'A','a' -> 'A'
+        };
+
+        let dfa = Dfa::new(vec!['A'], vec!['a'], transitions, vec!['A'], vec!['A'], Default::default(), Default::default()).unwrap();
+
+        assert_eq!(dfa.to_regex().to_string(), "a*");
+
+        let transitions = transitions!{
+            'A','a' -> 'B';
+            'B','b' -> 'A'
+        };
+
+        let dfa = Dfa::new(vec!['A', 'B'], vec!['a', 'b'], transitions, vec!['A'], vec!['B'], Default::default(), Default::default()).unwrap();
+
+        assert_eq!(dfa.to_regex().to_string(), "a(ba)*");
+
+        let nfa = Nfa::new(vec!['A'], vec!['a'], Default::default(), vec!['A'], vec![]).unwrap();
+
+        assert_eq!(nfa.to_regex(), Regex::Empty);
+
+        // Выражение, полученное по автомату, задаёт тот же язык
+        let mut dfa = Regex::parse("(a|b)*abb").unwrap().to_nfa::<u32>().to_deterministic();
+        dfa.minify();
+
+        let regex = dfa.to_regex();
+
+        println!("{}", regex);
+
+        let reparsed = Regex::parse(&regex.to_string()).expect("Printed regex should be parsable");
+
+        assert_eq!(reparsed, regex);
+
+        let mut reparsed_dfa = reparsed.to_nfa::<u32>().to_deterministic();
+        reparsed_dfa.minify();
+
+        assert_eq!(reparsed_dfa.states.len(), 4);
+
+        let nfa: Nfa<u32, char> = Regex::parse("a(b|ε)c*").unwrap().to_nfa();
+        let regex = nfa.to_regex();
+
+        println!("{}", regex);
+
+        let mut dfa = regex.to_nfa::<u32>().to_deterministic();
+        dfa.minify();
+
+        assert_eq!(dfa.states.len(), 3);
+
+        // Результат не зависит от порядка обхода ε-переходов
+        let expected = nfa.to_regex().to_string();
+
+        for _ in 0..10 {
+            let nfa: Nfa<u32, char> = Regex::parse("a(b|ε)c*").unwrap().to_nfa();
+
+            assert_eq!(nfa.to_regex().to_string(), expected);
+        }
+    }
+}
@@ -0,0 +1,205 @@
+use super::Regex;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum RegexError {
+    // Означает, что в позиции position встретился неожиданный символ.
+    UnexpectedSymbol { position: usize, symbol: char },
+    // Означает, что выражение закончилось раньше, чем ожидалось.
+    UnexpectedEnd,
+    // Означает, что в классе символов задан диапазон, начало которого больше конца.
+    InvalidRange { position: usize },
+    // Означает, что класс символов, начинающийся в позиции position, пуст.
+    EmptyClass { position: usize },
+}
+
+/// Разбор регулярного выражения методом рекурсивного спуска.
+pub(super) struct Parser {
+    symbols: Vec<char>,
+    position: usize,
+}
+
+impl Parser {
+    pub(super) fn new(regex: &str) -> Self {
+        Self {
+            symbols: regex.chars().collect(),
+            position: 0
+        }
+    }
+
+    pub(super) fn parse(mut self) -> Result<Regex, RegexError> {
+        let regex = self.parse_union()?;
+
+        match self.peek() {
+            Some(symbol) => Err(RegexError::UnexpectedSymbol { position: self.position, symbol }),
+            None => Ok(regex)
+        }
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        while self.symbols.get(self.position) == Some(&' ') {
+            self.position += 1;
+        }
+
+        self.symbols.get(self.position).cloned()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let symbol = self.peek();
+        self.position += 1;
+
+        symbol
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), RegexError> {
+        match self.next() {
+            Some(symbol) if symbol == expected => Ok(()),
+            Some(symbol) => Err(RegexError::UnexpectedSymbol { position: self.position - 1, symbol }),
+            None => Err(RegexError::UnexpectedEnd)
+        }
+    }
+
+    /// union := concat ('|' concat)*
+    fn parse_union(&mut self) -> Result<Regex, RegexError> {
+        let mut regex = self.parse_concat()?;
+
+        while self.peek() == Some('|') {
+            self.position += 1;
+
+            regex = Regex::Union(Box::new(regex), Box::new(self.parse_concat()?));
+        }
+
+        Ok(regex)
+    }
+
+    /// concat := postfix*
+    fn parse_concat(&mut self) -> Result<Regex, RegexError> {
+        let mut regex = None;
+
+        while let Some(symbol) = self.peek() {
+            if symbol == '|' || symbol == ')' { break; }
+
+            let operand = self.parse_postfix()?;
+
+            regex = Some(match regex {
+                Some(regex) => Regex::Concat(Box::new(regex), Box::new(operand)),
+                None => operand
+            });
+        }
+
+        Ok(regex.unwrap_or(Regex::Epsilon))
+    }
+
+    /// postfix := atom ('*' | '+' | '?')*
+    fn parse_postfix(&mut self) -> Result<Regex, RegexError> {
+        let mut regex = self.parse_atom()?;
+
+        loop {
+            regex = match self.peek() {
+                Some('*') => Regex::Star(Box::new(regex)),
+                Some('+') => Regex::Plus(Box::new(regex)),
+                Some('?') => Regex::Optional(Box::new(regex)),
+                _ => break
+            };
+
+            self.position += 1;
+        }
+
+        Ok(regex)
+    }
+
+    /// atom := '(' union ')' | '[' class ']' | 'ε' | '∅' | '\' symbol | symbol
+    fn parse_atom(&mut self) -> Result<Regex, RegexError> {
+        // Позиция атома берётся после пропуска пробелов
+        self.peek();
+
+        let position = self.position;
+
+        match self.next() {
+            Some('(') => {
+                let regex = self.parse_union()?;
+
+                self.expect(')')?;
+
+                Ok(regex)
+            },
+            Some('[') => self.parse_class(position),
+            Some('\\') => self.parse_escaped().map(Regex::Symbol),
+            Some(Regex::EMPTY_SEQUENCE) => Ok(Regex::Epsilon),
+            Some(Regex::EMPTY_SET) => Ok(Regex::Empty),
+            Some(symbol) if Regex::SPECIAL_SYMBOLS.contains(&symbol) => {
+                Err(RegexError::UnexpectedSymbol { position, symbol })
+            },
+            Some(symbol) => Ok(Regex::Symbol(symbol)),
+            None => Err(RegexError::UnexpectedEnd)
+        }
+    }
+
+    /// Символ после '\', пробелы внутри не пропускаются.
+    fn parse_escaped(&mut self) -> Result<char, RegexError> {
+        let symbol = self.symbols.get(self.position).cloned().ok_or(RegexError::UnexpectedEnd)?;
+        self.position += 1;
+
+        Ok(symbol)
+    }
+
+    /// class := (symbol | symbol '-' symbol)+ ']'
+    fn parse_class(&mut self, start: usize) -> Result<Regex, RegexError> {
+        let mut class = vec![];
+
+        loop {
+            let position = self.position;
+
+            let symbol = match self.symbols.get(self.position).cloned() {
+                Some(']') => {
+                    self.position += 1;
+                    break;
+                },
+                Some('\\') => {
+                    self.position += 1;
+                    self.parse_escaped()?
+                },
+                Some(symbol) => {
+                    self.position += 1;
+                    symbol
+                },
+                None => return Err(RegexError::UnexpectedEnd)
+            };
+
+            let is_range = self.symbols.get(self.position) == Some(&'-') &&
+                !matches!(self.symbols.get(self.position + 1), Some(']') | None);
+
+            if is_range {
+                self.position += 1;
+
+                let end = match self.symbols.get(self.position).cloned() {
+                    Some('\\') => {
+                        self.position += 1;
+                        self.parse_escaped()?
+                    },
+                    Some(end) => {
+                        self.position += 1;
+                        end
+                    },
+                    None => return Err(RegexError::UnexpectedEnd)
+                };
+
+                if end < symbol {
+                    return Err(RegexError::InvalidRange { position });
+                }
+
+                class.extend(symbol..=end);
+            } else {
+                class.push(symbol);
+            }
+        }
+
+        class.sort();
+        class.dedup();
+
+        match class.len() {
+            0 => Err(RegexError::EmptyClass { position: start }),
+            1 => Ok(Regex::Symbol(class[0])),
+            _ => Ok(Regex::Class(class))
+        }
+    }
+}
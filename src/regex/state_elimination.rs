@@ -0,0 +1,162 @@
+use std::{collections::{BTreeMap, HashMap}, hash::Hash};
+
+use crate::fsa::{Dfa, Nfa};
+
+use super::Regex;
+
+/// Обобщённый конечный автомат (ОКА), дуги которого помечены регулярными выражениями.
+/// 
+/// Состояния исходного автомата пронумерованы от 0 до n - 1,
+/// состояние n - новое начальное, n + 1 - новое заключительное.
+struct Gnfa {
+    states_count: usize,
+    edges: BTreeMap<(usize, usize), Regex>,
+}
+
+impl Gnfa {
+    fn new<State: Eq + Hash>(
+        states: &[State], 
+        edges: impl Iterator<Item = (State, Regex, State)>,
+        starting_states: &[State],
+        closing_states: &[State]
+    ) -> Self {
+        let state_to_idx = states.iter()
+            .enumerate()
+            .map(|(idx, state)| (state, idx))
+            .collect::<HashMap<&State, usize>>();
+
+        let states_count = states.len();
+
+        let mut gnfa = Self {
+            states_count,
+            edges: BTreeMap::new()
+        };
+
+        for (in_state, regex, out_state) in edges {
+            if let (Some(in_idx), Some(out_idx)) = (state_to_idx.get(&in_state), state_to_idx.get(&out_state)) {
+                gnfa.add_edge(*in_idx, *out_idx, regex);
+            }
+        }
+
+        starting_states.iter()
+            .filter_map(|state| state_to_idx.get(state))
+            .for_each(|idx| gnfa.add_edge(states_count, *idx, Regex::Epsilon));
+
+        closing_states.iter()
+            .filter_map(|state| state_to_idx.get(state))
+            .for_each(|idx| gnfa.add_edge(*idx, states_count + 1, Regex::Epsilon));
+
+        gnfa
+    }
+
+    fn add_edge(&mut self, in_idx: usize, out_idx: usize, regex: Regex) {
+        let regex = match self.edges.remove(&(in_idx, out_idx)) {
+            Some(existing) => Regex::union(existing, regex),
+            None => regex
+        };
+
+        self.edges.insert((in_idx, out_idx), regex);
+    }
+
+    /// Последовательно исключает все состояния исходного автомата:
+    /// для каждой пары дуг i -> k -> j добавляется дуга i -> j с выражением R(i,k) R(k,k)* R(k,j).
+    fn eliminate_states(mut self) -> Regex {
+        for k in 0..self.states_count {
+            let loop_regex = self.edges.remove(&(k, k))
+                .map(Regex::star)
+                .unwrap_or(Regex::Epsilon);
+
+            let incoming = self.edges.keys()
+                .filter(|(_, out_idx)| *out_idx == k)
+                .cloned()
+                .collect::<Vec<(usize, usize)>>()
+                .into_iter()
+                .map(|key| (key.0, self.edges.remove(&key).unwrap()))
+                .collect::<Vec<(usize, Regex)>>();
+
+            let outgoing = self.edges.keys()
+                .filter(|(in_idx, _)| *in_idx == k)
+                .cloned()
+                .collect::<Vec<(usize, usize)>>()
+                .into_iter()
+                .map(|key| (key.1, self.edges.remove(&key).unwrap()))
+                .collect::<Vec<(usize, Regex)>>();
+
+            for (in_idx, in_regex) in &incoming {
+                for (out_idx, out_regex) in &outgoing {
+                    let regex = Regex::concat(
+                        Regex::concat(in_regex.clone(), loop_regex.clone()), 
+                        out_regex.clone()
+                    );
+
+                    self.add_edge(*in_idx, *out_idx, regex);
+                }
+            }
+        }
+
+        self.edges.remove(&(self.states_count, self.states_count + 1))
+            .unwrap_or(Regex::Empty)
+    }
+}
+
+/// Переходы в порядке возрастания (состояние, символ, состояние),
+/// чтобы результат не зависел от порядка обхода таблицы переходов.
+fn sorted_transitions<'a, State: Ord + Clone + 'a>(
+    transitions: impl Iterator<Item = (&'a (State, char), &'a Vec<State>)>
+) -> impl Iterator<Item = (State, Regex, State)> {
+    let mut transitions = transitions
+        .flat_map(|((in_state, input), out_states)| {
+            out_states.iter()
+                .map(|out_state| (in_state.clone(), *input, out_state.clone()))
+        })
+        .collect::<Vec<(State, char, State)>>();
+
+    transitions.sort();
+
+    transitions.into_iter()
+        .map(|(in_state, input, out_state)| (in_state, Regex::Symbol(input), out_state))
+}
+
+impl<State> Nfa<State, char> 
+    where State: Eq + Hash + Ord + Clone
+{
+    /// Построение регулярного выражения методом исключения состояний.
+    pub fn to_regex(&self) -> Regex {
+        let transitions = sorted_transitions(self.transitions.iter());
+
+        let mut epsilon_transitions = self.epsilon_transitions.iter()
+            .flat_map(|(in_state, out_states)| {
+                out_states.iter()
+                    .map(|out_state| (in_state.clone(), out_state.clone()))
+            })
+            .collect::<Vec<(State, State)>>();
+
+        epsilon_transitions.sort();
+
+        let epsilon_transitions = epsilon_transitions.into_iter()
+            .map(|(in_state, out_state)| (in_state, Regex::Epsilon, out_state));
+
+        Gnfa::new(
+            &self.states, 
+            transitions.chain(epsilon_transitions), 
+            &self.starting_states, 
+            &self.closing_states
+        ).eliminate_states()
+    }
+}
+
+impl<State> Dfa<State, char> 
+    where State: Eq + Hash + Ord + Clone
+{
+    /// Построение регулярного выражения методом исключения состояний.
+    pub fn to_regex(&self) -> Regex {
+        let transitions = sorted_transitions(self.transitions.iter());
+
+        Gnfa::new(
+            &self.states, 
+            transitions, 
+            &self.starting_states, 
+            &self.closing_states
+        ).eliminate_states()
+    }
+}
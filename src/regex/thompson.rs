@@ -0,0 +1,129 @@
+use std::hash::Hash;
+
+use crate::fsa::{FromStateId, Nfa, StateAllocator, StateTransitionTable};
+
+use super::Regex;
+
+/// Фрагмент автомата с единственным входом и единственным выходом.
+struct Fragment<State> {
+    start: State,
+    end: State,
+}
+
+fn new_state<State>(nfa: &mut Nfa<State, char>, allocator: &mut StateAllocator<State>) -> State 
+    where State: FromStateId + Eq + Hash + Ord + Clone
+{
+    let state = allocator.allocate();
+    nfa.states.push(state.clone());
+
+    state
+}
+
+impl Regex {
+    /// Построение ε-НКА по методу Томпсона.
+    /// 
+    /// Полученный автомат имеет одно начальное и одно заключительное состояние,
+    /// а его алфавит состоит из символов, встречающихся в выражении.
+    pub fn to_nfa<State>(&self) -> Nfa<State, char> 
+        where State: FromStateId + Eq + Hash + Ord + Clone
+    {
+        let mut nfa = Nfa::new(
+            vec![], 
+            self.symbols(), 
+            StateTransitionTable::new(), 
+            vec![], 
+            vec![]
+        ).unwrap();
+
+        let mut allocator = StateAllocator::default();
+
+        let fragment = self.build_fragment(&mut nfa, &mut allocator);
+
+        nfa.starting_states.push(fragment.start);
+        nfa.closing_states.push(fragment.end);
+
+        nfa
+    }
+
+    fn build_fragment<State>(
+        &self, 
+        nfa: &mut Nfa<State, char>, 
+        allocator: &mut StateAllocator<State>
+    ) -> Fragment<State> 
+        where State: FromStateId + Eq + Hash + Ord + Clone
+    {
+        match self {
+            Regex::Empty => {
+                Fragment { start: new_state(nfa, allocator), end: new_state(nfa, allocator) }
+            },
+            Regex::Epsilon => {
+                let (start, end) = (new_state(nfa, allocator), new_state(nfa, allocator));
+
+                nfa.add_epsilon_transition(start.clone(), end.clone());
+
+                Fragment { start, end }
+            },
+            Regex::Symbol(symbol) => {
+                let (start, end) = (new_state(nfa, allocator), new_state(nfa, allocator));
+
+                nfa.add_transition(start.clone(), *symbol, end.clone());
+
+                Fragment { start, end }
+            },
+            Regex::Class(class) => {
+                let (start, end) = (new_state(nfa, allocator), new_state(nfa, allocator));
+
+                for symbol in class {
+                    nfa.add_transition(start.clone(), *symbol, end.clone());
+                }
+
+                Fragment { start, end }
+            },
+            Regex::Concat(left, right) => {
+                let left = left.build_fragment(nfa, allocator);
+                let right = right.build_fragment(nfa, allocator);
+
+                nfa.add_epsilon_transition(left.end, right.start);
+
+                Fragment { start: left.start, end: right.end }
+            },
+            Regex::Union(left, right) => {
+                let start = new_state(nfa, allocator);
+
+                let left = left.build_fragment(nfa, allocator);
+                let right = right.build_fragment(nfa, allocator);
+
+                let end = new_state(nfa, allocator);
+
+                nfa.add_epsilon_transition(start.clone(), left.start);
+                nfa.add_epsilon_transition(start.clone(), right.start);
+                nfa.add_epsilon_transition(left.end, end.clone());
+                nfa.add_epsilon_transition(right.end, end.clone());
+
+                Fragment { start, end }
+            },
+            Regex::Star(regex) | Regex::Plus(regex) | Regex::Optional(regex) => {
+                let start = new_state(nfa, allocator);
+
+                let inner = regex.build_fragment(nfa, allocator);
+
+                let end = new_state(nfa, allocator);
+
+                nfa.add_epsilon_transition(start.clone(), inner.start.clone());
+                nfa.add_epsilon_transition(inner.end.clone(), end.clone());
+
+                // r* и r? допускают пустую цепочку
+                if !matches!(self, Regex::Plus(_)) {
+                    nfa.add_epsilon_transition(start.clone(), end.clone());
+                }
+
+                // r* и r+ допускают повторение
+                if !matches!(self, Regex::Optional(_)) {
+                    nfa.add_epsilon_transition(inner.end, inner.start);
+                }
+
+                Fragment { start, end }
+            },
+        }
+    }
+}
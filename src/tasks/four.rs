@@ -28,9 +28,16 @@ fn four() {
         println!("{}", grammar);
         println!("{}", grammar.grammar_type);
 
-        // grammar.remove_unreachable_symbols();
+        grammar.remove_unreachable_symbols();
 
-        // println!("{}", grammar);
-        // println!("{}", grammar.grammar_type);
+        println!("{}", grammar);
+        println!("{}", grammar.grammar_type);
+
+        grammar.eliminate_left_recursion();
+
+        println!("{}", grammar);
+        println!("{}", grammar.grammar_type);
+
+        assert!(!grammar.is_left_recursive(), "Left recursion should be eliminated, got: {}", grammar);
     }
 }
\ No newline at end of file